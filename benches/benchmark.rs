@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::time::Duration;
 
@@ -128,6 +129,69 @@ fn table_traversal_sequence(c: &mut Criterion) {
     });
 }
 
+fn table_into_lua_vec(c: &mut Criterion) {
+    let lua = Lua::new();
+
+    let vec = (0..1_000_000).map(|i| i as f64).collect::<Vec<f64>>();
+
+    c.bench_function("table [into_lua Vec<f64> 1M]", |b| {
+        b.iter_batched(
+            || {
+                collect_gc_twice(&lua);
+                vec.clone()
+            },
+            |vec| vec.into_lua(&lua).unwrap(),
+            BatchSize::LargeInput,
+        );
+    });
+}
+
+fn table_into_lua_vec_memory_limit(c: &mut Criterion) {
+    let lua = Lua::new();
+    lua.set_memory_limit(1 << 30).unwrap();
+
+    let vec = (0..1_000_000).map(|i| i as f64).collect::<Vec<f64>>();
+
+    c.bench_function("table [into_lua Vec<f64> 1M with memory limit]", |b| {
+        b.iter_batched(
+            || {
+                collect_gc_twice(&lua);
+                vec.clone()
+            },
+            |vec| vec.into_lua(&lua).unwrap(),
+            BatchSize::LargeInput,
+        );
+    });
+}
+
+fn table_from_lua_vec(c: &mut Criterion) {
+    let lua = Lua::new();
+
+    let table = lua.create_sequence_from(1..=100_000).unwrap();
+
+    c.bench_function("table [from_lua Vec 100k]", |b| {
+        b.iter_batched(
+            || LuaValue::Table(table.clone()),
+            |value| lua.unpack::<Vec<i64>>(value).unwrap(),
+            BatchSize::SmallInput,
+        );
+    });
+}
+
+fn table_from_lua_hashmap(c: &mut Criterion) {
+    let lua = Lua::new();
+
+    let table = lua.create_table_from((1..=10_000).map(|i| (i, i))).unwrap();
+
+    c.bench_function("table [from_lua HashMap 10k]", |b| {
+        b.iter_batched(
+            || LuaValue::Table(table.clone()),
+            |value| lua.unpack::<HashMap<i64, i64>>(value).unwrap(),
+            BatchSize::SmallInput,
+        );
+    });
+}
+
 fn function_create(c: &mut Criterion) {
     let lua = Lua::new();
 
@@ -160,6 +224,42 @@ fn function_call_sum(c: &mut Criterion) {
     });
 }
 
+fn function_call_variadic_sum(c: &mut Criterion) {
+    let lua = Lua::new();
+
+    let sum = lua
+        .create_function(|_, args: LuaVariadic<i64>| Ok(args.iter().sum::<i64>()))
+        .unwrap();
+
+    c.bench_function("function [call Rust sum (Variadic)]", |b| {
+        b.iter_batched(
+            || collect_gc_twice(&lua),
+            |_| {
+                assert_eq!(sum.call::<i64>((1, 2, 3, 4, 5, 6, 7, 8, 9, 10)).unwrap(), 55);
+            },
+            BatchSize::SmallInput,
+        );
+    });
+}
+
+fn function_call_rest_args_sum(c: &mut Criterion) {
+    let lua = Lua::new();
+
+    let sum = lua
+        .create_function(|_, args: mlua::RestArgs<i64>| args.sum::<LuaResult<i64>>())
+        .unwrap();
+
+    c.bench_function("function [call Rust sum (RestArgs)]", |b| {
+        b.iter_batched(
+            || collect_gc_twice(&lua),
+            |_| {
+                assert_eq!(sum.call::<i64>((1, 2, 3, 4, 5, 6, 7, 8, 9, 10)).unwrap(), 55);
+            },
+            BatchSize::SmallInput,
+        );
+    });
+}
+
 fn function_call_lua_sum(c: &mut Criterion) {
     let lua = Lua::new();
 
@@ -399,9 +499,15 @@ criterion_group! {
         table_traversal_pairs,
         table_traversal_for_each,
         table_traversal_sequence,
+        table_into_lua_vec,
+        table_into_lua_vec_memory_limit,
+        table_from_lua_vec,
+        table_from_lua_hashmap,
 
         function_create,
         function_call_sum,
+        function_call_variadic_sum,
+        function_call_rest_args_sum,
         function_call_lua_sum,
         function_call_concat,
         function_call_lua_concat,
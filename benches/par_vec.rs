@@ -0,0 +1,60 @@
+use std::time::Duration;
+
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+
+use mlua::prelude::*;
+use mlua::ParVec;
+
+fn collect_gc_twice(lua: &Lua) {
+    lua.gc_collect().unwrap();
+    lua.gc_collect().unwrap();
+}
+
+fn create_table(lua: &Lua) -> LuaTable {
+    lua.load("local t = {} for i = 1, 1000000 do t[i] = i * 0.5 end return t")
+        .eval()
+        .unwrap()
+}
+
+fn vec_from_table(c: &mut Criterion) {
+    let lua = Lua::new();
+    let table = create_table(&lua);
+
+    c.bench_function("vec [from table] 1M", |b| {
+        b.iter_batched(
+            || collect_gc_twice(&lua),
+            |_| {
+                lua.convert::<Vec<f64>>(&table).unwrap();
+            },
+            BatchSize::SmallInput,
+        );
+    });
+}
+
+fn par_vec_from_table(c: &mut Criterion) {
+    let lua = Lua::new();
+    let table = create_table(&lua);
+
+    c.bench_function("par_vec [from table] 1M", |b| {
+        b.iter_batched(
+            || collect_gc_twice(&lua),
+            |_| {
+                lua.convert::<ParVec<f64>>(&table).unwrap();
+            },
+            BatchSize::SmallInput,
+        );
+    });
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default()
+        .sample_size(20)
+        .measurement_time(Duration::from_secs(10))
+        .noise_threshold(0.02);
+    targets =
+        vec_from_table,
+        par_vec_from_table,
+}
+
+criterion_main!(benches);
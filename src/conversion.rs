@@ -1,25 +1,38 @@
 use std::borrow::Cow;
-use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::cell::{Cell, RefCell};
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, LinkedList, VecDeque};
+use std::convert::Infallible;
 use std::ffi::{CStr, CString, OsStr, OsString};
 use std::hash::{BuildHasher, Hash};
+use std::marker::PhantomData;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128, NonZeroU16,
+    NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize, Wrapping,
+};
+use std::ops::{Bound, Range, RangeInclusive};
 use std::os::raw::c_int;
 use std::path::{Path, PathBuf};
+use std::rc::Rc;
 use std::string::String as StdString;
-use std::{mem, slice, str};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use std::{fmt, mem, slice, str};
 
 use bstr::{BStr, BString, ByteSlice, ByteVec};
 use num_traits::cast;
 
-use crate::error::{Error, Result};
+use crate::error::{Error, ErrorContext as _, Result};
 use crate::function::Function;
 use crate::state::{Lua, RawLua};
 use crate::string::{BorrowedBytes, BorrowedStr, String};
 use crate::table::Table;
 use crate::thread::Thread;
 use crate::traits::{FromLua, IntoLua, ShortTypeName as _};
-use crate::types::{Either, LightUserData, MaybeSend, RegistryKey};
+use crate::types::{Either, LightUserData, MaybeSend, RegistryKey, StrictBool};
 use crate::userdata::{AnyUserData, UserData};
-use crate::value::{Nil, Value};
+use crate::value::{Nil, PlainValue, Value};
 
 impl IntoLua for Value {
     #[inline]
@@ -47,6 +60,81 @@ impl FromLua for Value {
     }
 }
 
+impl IntoLua for PlainValue {
+    fn into_lua(self, lua: &Lua) -> Result<Value> {
+        match self {
+            PlainValue::Nil => Ok(Nil),
+            PlainValue::Boolean(b) => Ok(Value::Boolean(b)),
+            PlainValue::Integer(i) => Ok(Value::Integer(i)),
+            PlainValue::Number(n) => Ok(Value::Number(n)),
+            PlainValue::String(s) => lua.create_string(s).map(Value::String),
+            PlainValue::Array(values) => lua.create_sequence_from(values).map(Value::Table),
+            PlainValue::Map(pairs) => lua.create_table_from(pairs).map(Value::Table),
+        }
+    }
+}
+
+impl FromLua for PlainValue {
+    #[inline]
+    fn from_lua(value: Value, _: &Lua) -> Result<Self> {
+        value.to_plain()
+    }
+}
+
+/// Converts a plain value into a number following the same rules as [`FromLua`], except that
+/// strings are not coerced.
+macro_rules! plain_convert_number {
+    ($x:ty) => {
+        impl TryFrom<PlainValue> for $x {
+            type Error = Error;
+
+            fn try_from(value: PlainValue) -> Result<Self> {
+                let ty = value.type_name();
+                (match value {
+                    PlainValue::Integer(i) => cast(i),
+                    PlainValue::Number(n) => cast(n),
+                    _ => {
+                        return Err(Error::FromLuaConversionError {
+                            from: ty,
+                            to: stringify!($x).to_string(),
+                            message: Some("expected number".to_string()),
+                        })
+                    }
+                })
+                .ok_or_else(|| Error::FromLuaConversionError {
+                    from: ty,
+                    to: stringify!($x).to_string(),
+                    message: Some("out of range".to_owned()),
+                })
+            }
+        }
+    };
+}
+
+plain_convert_number!(i8);
+plain_convert_number!(u8);
+plain_convert_number!(i16);
+plain_convert_number!(u16);
+plain_convert_number!(i32);
+plain_convert_number!(u32);
+plain_convert_number!(i64);
+plain_convert_number!(u64);
+plain_convert_number!(i128);
+plain_convert_number!(u128);
+plain_convert_number!(isize);
+plain_convert_number!(usize);
+plain_convert_number!(f32);
+plain_convert_number!(f64);
+
+impl TryFrom<PlainValue> for bool {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(value: PlainValue) -> Result<Self> {
+        Ok(!matches!(value, PlainValue::Nil | PlainValue::Boolean(false)))
+    }
+}
+
 impl IntoLua for String {
     #[inline]
     fn into_lua(self, _: &Lua) -> Result<Value> {
@@ -562,6 +650,24 @@ impl IntoLua for Cow<'_, str> {
     fn into_lua(self, lua: &Lua) -> Result<Value> {
         Ok(Value::String(lua.create_string(self.as_bytes())?))
     }
+
+    #[inline]
+    unsafe fn push_into_stack(self, lua: &RawLua) -> Result<()> {
+        push_bytes_into_stack(self.as_ref(), lua)
+    }
+}
+
+/// Converts a Lua string (or number) into an owned string.
+impl FromLua for Cow<'static, str> {
+    #[inline]
+    fn from_lua(value: Value, lua: &Lua) -> Result<Self> {
+        StdString::from_lua(value, lua).map(Cow::Owned)
+    }
+
+    #[inline]
+    unsafe fn from_stack(idx: c_int, lua: &RawLua) -> Result<Self> {
+        StdString::from_stack(idx, lua).map(Cow::Owned)
+    }
 }
 
 impl IntoLua for Box<str> {
@@ -571,6 +677,32 @@ impl IntoLua for Box<str> {
     }
 }
 
+/// Converts the shared string into a Lua string without building an intermediate `String`.
+impl IntoLua for Arc<str> {
+    #[inline]
+    fn into_lua(self, lua: &Lua) -> Result<Value> {
+        Ok(Value::String(lua.create_string(&*self)?))
+    }
+
+    #[inline]
+    unsafe fn push_into_stack(self, lua: &RawLua) -> Result<()> {
+        push_bytes_into_stack(self.as_bytes(), lua)
+    }
+}
+
+/// Converts the shared string into a Lua string without building an intermediate `String`.
+impl IntoLua for Rc<str> {
+    #[inline]
+    fn into_lua(self, lua: &Lua) -> Result<Value> {
+        Ok(Value::String(lua.create_string(&*self)?))
+    }
+
+    #[inline]
+    unsafe fn push_into_stack(self, lua: &RawLua) -> Result<()> {
+        push_bytes_into_stack(self.as_bytes(), lua)
+    }
+}
+
 impl FromLua for Box<str> {
     #[inline]
     fn from_lua(value: Value, lua: &Lua) -> Result<Self> {
@@ -632,6 +764,14 @@ impl IntoLua for Cow<'_, CStr> {
     }
 }
 
+/// Converts a Lua string (or number) into an owned C string.
+impl FromLua for Cow<'static, CStr> {
+    #[inline]
+    fn from_lua(value: Value, lua: &Lua) -> Result<Self> {
+        CString::from_lua(value, lua).map(Cow::Owned)
+    }
+}
+
 impl IntoLua for BString {
     #[inline]
     fn into_lua(self, lua: &Lua) -> Result<Value> {
@@ -688,6 +828,22 @@ impl IntoLua for &BStr {
     }
 }
 
+/// Converts the bytes into a Lua string.
+///
+/// Plain `&[u8]` and `Vec<u8>` are converted into tables of integers by the generic slice and
+/// [`Vec`] impls. Use [`BStr`] or [`BString`] to convert them into Lua strings instead.
+impl IntoLua for Cow<'_, [u8]> {
+    #[inline]
+    fn into_lua(self, lua: &Lua) -> Result<Value> {
+        Ok(Value::String(lua.create_string(&*self)?))
+    }
+
+    #[inline]
+    unsafe fn push_into_stack(self, lua: &RawLua) -> Result<()> {
+        push_bytes_into_stack(self, lua)
+    }
+}
+
 impl IntoLua for OsString {
     #[inline]
     fn into_lua(self, lua: &Lua) -> Result<Value> {
@@ -722,27 +878,133 @@ impl IntoLua for &OsStr {
     }
 }
 
+/// Converts the path into a Lua string.
+///
+/// On Unix the raw bytes of the path are used, so paths that are not valid UTF-8 survive a round
+/// trip through Lua. On other platforms (eg. Windows) the path is converted to UTF-8 lossily and
+/// invalid sequences are replaced with `U+FFFD`.
 impl IntoLua for PathBuf {
     #[inline]
     fn into_lua(self, lua: &Lua) -> Result<Value> {
-        self.as_os_str().into_lua(lua)
+        self.as_path().into_lua(lua)
     }
 }
 
+/// Converts a Lua string into a path.
+///
+/// Numbers are coerced to strings, like for the other string types. On Unix the string bytes are
+/// used as is, on other platforms (eg. Windows) they are decoded as UTF-8 lossily.
 impl FromLua for PathBuf {
-    #[inline]
     fn from_lua(value: Value, lua: &Lua) -> Result<Self> {
-        OsString::from_lua(value, lua).map(PathBuf::from)
+        #[cfg(unix)]
+        {
+            OsString::from_lua(value, lua).map(PathBuf::from)
+        }
+        #[cfg(not(unix))]
+        {
+            let bs = BString::from_lua(value, lua)?;
+            Ok(PathBuf::from(bs.to_str_lossy().into_owned()))
+        }
     }
 }
 
+/// Converts the path into a Lua string, with the same platform caveats as for `PathBuf`.
 impl IntoLua for &Path {
+    fn into_lua(self, lua: &Lua) -> Result<Value> {
+        #[cfg(unix)]
+        {
+            use std::os::unix::ffi::OsStrExt as _;
+            Ok(Value::String(lua.create_string(self.as_os_str().as_bytes())?))
+        }
+        #[cfg(not(unix))]
+        {
+            Ok(Value::String(lua.create_string(&*self.to_string_lossy())?))
+        }
+    }
+}
+
+/// Implements `IntoLua` for `&mut T` by reborrowing it as `&T`.
+macro_rules! lua_convert_mut_ref {
+    ($($(#[$meta:meta])* $x:ty),* $(,)?) => {
+        $(
+            $(#[$meta])*
+            impl IntoLua for &mut $x {
+                #[inline]
+                fn into_lua(self, lua: &Lua) -> Result<Value> {
+                    (&*self).into_lua(lua)
+                }
+
+                #[inline]
+                unsafe fn push_into_stack(self, lua: &RawLua) -> Result<()> {
+                    (&*self).push_into_stack(lua)
+                }
+            }
+        )*
+    };
+}
+
+lua_convert_mut_ref!(
+    Value,
+    String,
+    BorrowedStr<'_>,
+    BorrowedBytes<'_>,
+    Table,
+    Function,
+    Thread,
+    AnyUserData,
+    RegistryKey,
+    #[cfg(feature = "luau")]
+    crate::Buffer,
+    str,
+    CStr,
+    BStr,
+    OsStr,
+    Path,
+);
+
+impl<T: IntoLua + Clone> IntoLua for &mut [T] {
     #[inline]
     fn into_lua(self, lua: &Lua) -> Result<Value> {
-        self.as_os_str().into_lua(lua)
+        (&*self).into_lua(lua)
     }
 }
 
+macro_rules! lua_convert_net_addr {
+    ($x:ty) => {
+        impl IntoLua for $x {
+            #[inline]
+            fn into_lua(self, lua: &Lua) -> Result<Value> {
+                Ok(Value::String(lua.create_string(self.to_string())?))
+            }
+        }
+
+        impl FromLua for $x {
+            fn from_lua(value: Value, _: &Lua) -> Result<Self> {
+                let ty = value.type_name();
+                let conv_err = |message: StdString| Error::FromLuaConversionError {
+                    from: ty,
+                    to: stringify!($x).to_string(),
+                    message: Some(message),
+                };
+                match value {
+                    Value::String(s) => s
+                        .to_str()?
+                        .parse()
+                        .map_err(|err| conv_err(format!("{err}"))),
+                    _ => Err(conv_err("expected string".to_string())),
+                }
+            }
+        }
+    };
+}
+
+// Network addresses are represented in Lua by their canonical string form.
+// `SocketAddr` includes the port, eg. "127.0.0.1:8080" or "[::1]:8080".
+lua_convert_net_addr!(IpAddr);
+lua_convert_net_addr!(Ipv4Addr);
+lua_convert_net_addr!(Ipv6Addr);
+lua_convert_net_addr!(SocketAddr);
+
 impl IntoLua for char {
     #[inline]
     fn into_lua(self, lua: &Lua) -> Result<Value> {
@@ -768,16 +1030,18 @@ impl FromLua for char {
             Value::String(s) => {
                 let str = s.to_str()?;
                 let mut str_iter = str.chars();
-                match (str_iter.next(), str_iter.next()) {
-                    (Some(char), None) => Ok(char),
-                    _ => Err(Error::FromLuaConversionError {
-                        from: ty,
-                        to: "char".to_string(),
-                        message: Some(
-                            "expected string to have exactly one char when converting to char".to_string(),
-                        ),
-                    }),
-                }
+                let message = match (str_iter.next(), str_iter.next()) {
+                    (Some(char), None) => return Ok(char),
+                    (None, _) => "expected string to have exactly one char, got empty string",
+                    (Some(_), Some(_)) => {
+                        "expected string to have exactly one char, got more than one character"
+                    }
+                };
+                Err(Error::FromLuaConversionError {
+                    from: ty,
+                    to: "char".to_string(),
+                    message: Some(message.to_string()),
+                })
             }
             _ => Err(Error::FromLuaConversionError {
                 from: ty,
@@ -894,6 +1158,47 @@ lua_convert_int!(u128);
 lua_convert_int!(isize);
 lua_convert_int!(usize);
 
+macro_rules! lua_convert_nonzero {
+    ($x:ty, $inner:ty) => {
+        impl IntoLua for $x {
+            #[inline]
+            fn into_lua(self, lua: &Lua) -> Result<Value> {
+                self.get().into_lua(lua)
+            }
+
+            #[inline]
+            unsafe fn push_into_stack(self, lua: &RawLua) -> Result<()> {
+                self.get().push_into_stack(lua)
+            }
+        }
+
+        impl FromLua for $x {
+            #[inline]
+            fn from_lua(value: Value, lua: &Lua) -> Result<Self> {
+                let ty = value.type_name();
+                <$x>::new(<$inner>::from_lua(value, lua)?).ok_or_else(|| Error::FromLuaConversionError {
+                    from: ty,
+                    to: stringify!($x).to_string(),
+                    message: Some("value must be non-zero".to_string()),
+                })
+            }
+        }
+    };
+}
+
+lua_convert_nonzero!(NonZeroI8, i8);
+lua_convert_nonzero!(NonZeroU8, u8);
+lua_convert_nonzero!(NonZeroI16, i16);
+lua_convert_nonzero!(NonZeroU16, u16);
+lua_convert_nonzero!(NonZeroI32, i32);
+lua_convert_nonzero!(NonZeroU32, u32);
+lua_convert_nonzero!(NonZeroI64, i64);
+lua_convert_nonzero!(NonZeroU64, u64);
+lua_convert_nonzero!(NonZeroI128, i128);
+lua_convert_nonzero!(NonZeroU128, u128);
+lua_convert_nonzero!(NonZeroIsize, isize);
+lua_convert_nonzero!(NonZeroUsize, usize);
+
 macro_rules! lua_convert_float {
     ($x:ty) => {
         impl IntoLua for $x {
@@ -952,6 +1257,58 @@ macro_rules! lua_convert_float {
 lua_convert_float!(f32);
 lua_convert_float!(f64);
 
+/// Implements conversions for 16-bit floats, which are passed to Lua as `f64` numbers.
+///
+/// Converting from Lua rounds to the nearest representable value. Finite numbers that are too
+/// large in magnitude for the target type are rejected instead of becoming infinite.
+#[cfg(feature = "half")]
+macro_rules! lua_convert_half {
+    ($x:ty) => {
+        #[cfg_attr(docsrs, doc(cfg(feature = "half")))]
+        impl IntoLua for $x {
+            #[inline]
+            fn into_lua(self, _: &Lua) -> Result<Value> {
+                Ok(Value::Number(self.to_f64()))
+            }
+
+            #[inline]
+            unsafe fn push_into_stack(self, lua: &RawLua) -> Result<()> {
+                ffi::lua_pushnumber(lua.state(), self.to_f64());
+                Ok(())
+            }
+        }
+
+        #[cfg_attr(docsrs, doc(cfg(feature = "half")))]
+        impl FromLua for $x {
+            #[inline]
+            fn from_lua(value: Value, lua: &Lua) -> Result<Self> {
+                let ty = value.type_name();
+                let n = lua
+                    .coerce_number(value)?
+                    .ok_or_else(|| Error::FromLuaConversionError {
+                        from: ty,
+                        to: stringify!($x).to_string(),
+                        message: Some("expected number or string coercible to number".to_string()),
+                    })?;
+                let h = <$x>::from_f64(n);
+                if h.is_infinite() && n.is_finite() {
+                    return Err(Error::FromLuaConversionError {
+                        from: ty,
+                        to: stringify!($x).to_string(),
+                        message: Some("number out of range".to_string()),
+                    });
+                }
+                Ok(h)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "half")]
+lua_convert_half!(half::f16);
+#[cfg(feature = "half")]
+lua_convert_half!(half::bf16);
+
 impl<T> IntoLua for &[T]
 where
     T: IntoLua + Clone,
@@ -991,6 +1348,20 @@ where
                 ptr::write(arr[3].as_mut_ptr(), T::from_lua(Value::Number(v.w() as _), _lua)?);
                 Ok(mem::transmute_copy(&arr))
             },
+            #[cfg(feature = "luau")]
+            Value::Buffer(buf) => {
+                // Each byte is converted as an integer, so this is mostly useful for `[u8; N]`.
+                // The bytes are copied first, as `T::from_lua` may run Lua code that modifies the buffer.
+                let vec = (buf.to_vec().into_iter())
+                    .map(|b| T::from_lua(Value::Integer(b.into()), _lua))
+                    .collect::<Result<Vec<_>>>()?;
+                vec.try_into()
+                    .map_err(|vec: Vec<T>| Error::FromLuaConversionError {
+                        from: "buffer",
+                        to: Self::type_name(),
+                        message: Some(format!("expected buffer of length {N}, got {}", vec.len())),
+                    })
+            }
             Value::Table(table) => {
                 let vec = table.sequence_values().collect::<Result<Vec<_>>>()?;
                 vec.try_into()
@@ -1034,7 +1405,10 @@ impl<T: FromLua> FromLua for Vec<T> {
     #[inline]
     fn from_lua(value: Value, _lua: &Lua) -> Result<Self> {
         match value {
-            Value::Table(table) => table.sequence_values().collect(),
+            Value::Table(table) => {
+                let vec = Vec::with_capacity(capacity_hint(&table));
+                sequence_from_lua(&table, vec)
+            }
             _ => Err(Error::FromLuaConversionError {
                 from: value.type_name(),
                 to: Self::type_name(),
@@ -1044,65 +1418,70 @@ impl<T: FromLua> FromLua for Vec<T> {
     }
 }
 
-impl<K: Eq + Hash + IntoLua, V: IntoLua, S: BuildHasher> IntoLua for HashMap<K, V, S> {
+impl<T: IntoLua> IntoLua for VecDeque<T> {
     #[inline]
     fn into_lua(self, lua: &Lua) -> Result<Value> {
-        Ok(Value::Table(lua.create_table_from(self)?))
+        Ok(Value::Table(lua.create_sequence_from(self)?))
     }
 }
 
-impl<K: Eq + Hash + FromLua, V: FromLua, S: BuildHasher + Default> FromLua for HashMap<K, V, S> {
+impl<T: FromLua> FromLua for VecDeque<T> {
     #[inline]
-    fn from_lua(value: Value, _: &Lua) -> Result<Self> {
-        if let Value::Table(table) = value {
-            table.pairs().collect()
-        } else {
-            Err(Error::FromLuaConversionError {
+    fn from_lua(value: Value, _lua: &Lua) -> Result<Self> {
+        match value {
+            Value::Table(table) => {
+                let queue = VecDeque::with_capacity(capacity_hint(&table));
+                sequence_from_lua(&table, queue)
+            }
+            _ => Err(Error::FromLuaConversionError {
                 from: value.type_name(),
                 to: Self::type_name(),
                 message: Some("expected table".to_string()),
-            })
+            }),
         }
     }
 }
 
-impl<K: Ord + IntoLua, V: IntoLua> IntoLua for BTreeMap<K, V> {
+impl<T: IntoLua> IntoLua for LinkedList<T> {
     #[inline]
     fn into_lua(self, lua: &Lua) -> Result<Value> {
-        Ok(Value::Table(lua.create_table_from(self)?))
+        Ok(Value::Table(lua.create_sequence_from(self)?))
     }
 }
 
-impl<K: Ord + FromLua, V: FromLua> FromLua for BTreeMap<K, V> {
+impl<T: FromLua> FromLua for LinkedList<T> {
     #[inline]
-    fn from_lua(value: Value, _: &Lua) -> Result<Self> {
-        if let Value::Table(table) = value {
-            table.pairs().collect()
-        } else {
-            Err(Error::FromLuaConversionError {
+    fn from_lua(value: Value, _lua: &Lua) -> Result<Self> {
+        match value {
+            Value::Table(table) => sequence_from_lua(&table, LinkedList::new()),
+            _ => Err(Error::FromLuaConversionError {
                 from: value.type_name(),
                 to: Self::type_name(),
                 message: Some("expected table".to_string()),
-            })
+            }),
         }
     }
 }
 
-impl<T: Eq + Hash + IntoLua, S: BuildHasher> IntoLua for HashSet<T, S> {
+#[cfg(feature = "smallvec")]
+#[cfg_attr(docsrs, doc(cfg(feature = "smallvec")))]
+impl<T: IntoLua, const N: usize> IntoLua for smallvec::SmallVec<[T; N]> {
     #[inline]
     fn into_lua(self, lua: &Lua) -> Result<Value> {
-        Ok(Value::Table(
-            lua.create_table_from(self.into_iter().map(|val| (val, true)))?,
-        ))
+        Ok(Value::Table(lua.create_sequence_from(self)?))
     }
 }
 
-impl<T: Eq + Hash + FromLua, S: BuildHasher + Default> FromLua for HashSet<T, S> {
+#[cfg(feature = "smallvec")]
+#[cfg_attr(docsrs, doc(cfg(feature = "smallvec")))]
+impl<T: FromLua, const N: usize> FromLua for smallvec::SmallVec<[T; N]> {
     #[inline]
-    fn from_lua(value: Value, _: &Lua) -> Result<Self> {
+    fn from_lua(value: Value, _lua: &Lua) -> Result<Self> {
         match value {
-            Value::Table(table) if table.raw_len() > 0 => table.sequence_values().collect(),
-            Value::Table(table) => table.pairs::<T, Value>().map(|res| res.map(|(k, _)| k)).collect(),
+            Value::Table(table) => {
+                let vec = smallvec::SmallVec::with_capacity(capacity_hint(&table));
+                sequence_from_lua(&table, vec)
+            }
             _ => Err(Error::FromLuaConversionError {
                 from: value.type_name(),
                 to: Self::type_name(),
@@ -1112,41 +1491,569 @@ impl<T: Eq + Hash + FromLua, S: BuildHasher + Default> FromLua for HashSet<T, S>
     }
 }
 
-impl<T: Ord + IntoLua> IntoLua for BTreeSet<T> {
+#[cfg(feature = "arrayvec")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arrayvec")))]
+impl<T: IntoLua, const N: usize> IntoLua for arrayvec::ArrayVec<T, N> {
     #[inline]
     fn into_lua(self, lua: &Lua) -> Result<Value> {
-        Ok(Value::Table(
-            lua.create_table_from(self.into_iter().map(|val| (val, true)))?,
-        ))
+        Ok(Value::Table(lua.create_sequence_from(self)?))
     }
 }
 
-impl<T: Ord + FromLua> FromLua for BTreeSet<T> {
-    #[inline]
-    fn from_lua(value: Value, _: &Lua) -> Result<Self> {
-        match value {
-            Value::Table(table) if table.raw_len() > 0 => table.sequence_values().collect(),
-            Value::Table(table) => table.pairs::<T, Value>().map(|res| res.map(|(k, _)| k)).collect(),
-            _ => Err(Error::FromLuaConversionError {
-                from: value.type_name(),
-                to: Self::type_name(),
-                message: Some("expected table".to_string()),
-            }),
+/// Converts a Lua sequence into an `ArrayVec`.
+///
+/// Returns an error if the sequence has more than `N` elements.
+#[cfg(feature = "arrayvec")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arrayvec")))]
+impl<T: FromLua, const N: usize> FromLua for arrayvec::ArrayVec<T, N> {
+    fn from_lua(value: Value, _lua: &Lua) -> Result<Self> {
+        let table = match value {
+            Value::Table(table) => table,
+            _ => {
+                return Err(Error::FromLuaConversionError {
+                    from: value.type_name(),
+                    to: Self::type_name(),
+                    message: Some("expected table".to_string()),
+                })
+            }
+        };
+        let mut vec = arrayvec::ArrayVec::new();
+        for (i, res) in table.sequence_values().enumerate() {
+            if vec.is_full() {
+                return Err(Error::FromLuaConversionError {
+                    from: "table",
+                    to: Self::type_name(),
+                    message: Some(format!("too many elements, capacity is {N}")),
+                });
+            }
+            vec.push(res.map_err(|err| {
+                let value = table.raw_get(i + 1).unwrap_or(Nil);
+                conversion_error_at(format_args!("element #{}", i + 1), &value, err)
+            })?);
         }
+        Ok(vec)
     }
 }
 
-impl<T: IntoLua> IntoLua for Option<T> {
+impl<K: Eq + Hash + IntoLua, V: IntoLua, S: BuildHasher> IntoLua for HashMap<K, V, S> {
     #[inline]
     fn into_lua(self, lua: &Lua) -> Result<Value> {
-        match self {
-            Some(val) => val.into_lua(lua),
-            None => Ok(Nil),
-        }
+        Ok(Value::Table(lua.create_table_from(self)?))
     }
+}
 
+impl<K: Eq + Hash + FromLua, V: FromLua, S: BuildHasher + Default> FromLua for HashMap<K, V, S> {
     #[inline]
-    unsafe fn push_into_stack(self, lua: &RawLua) -> Result<()> {
+    fn from_lua(value: Value, lua: &Lua) -> Result<Self> {
+        if let Value::Table(table) = value {
+            let map = HashMap::with_capacity_and_hasher(capacity_hint(&table), S::default());
+            pairs_from_lua(&table, lua, map)
+        } else {
+            Err(Error::FromLuaConversionError {
+                from: value.type_name(),
+                to: Self::type_name(),
+                message: Some("expected table".to_string()),
+            })
+        }
+    }
+}
+
+/// Converts the map into a table, inserting the pairs in the map iteration order.
+///
+/// Lua tables do not preserve the order of non-array keys, so only keys forming a sequence
+/// `1..=n` will be iterated in the same order from Lua.
+#[cfg(feature = "indexmap")]
+#[cfg_attr(docsrs, doc(cfg(feature = "indexmap")))]
+impl<K: Eq + Hash + IntoLua, V: IntoLua, S: BuildHasher> IntoLua for indexmap::IndexMap<K, V, S> {
+    #[inline]
+    fn into_lua(self, lua: &Lua) -> Result<Value> {
+        Ok(Value::Table(lua.create_table_from(self)?))
+    }
+}
+
+/// Collects the table pairs into a map.
+///
+/// The resulting order follows the Lua table traversal order, which is unspecified except that
+/// sequence keys usually come first in ascending order.
+#[cfg(feature = "indexmap")]
+#[cfg_attr(docsrs, doc(cfg(feature = "indexmap")))]
+impl<K: Eq + Hash + FromLua, V: FromLua, S: BuildHasher + Default> FromLua for indexmap::IndexMap<K, V, S> {
+    #[inline]
+    fn from_lua(value: Value, lua: &Lua) -> Result<Self> {
+        if let Value::Table(table) = value {
+            let map = indexmap::IndexMap::with_capacity_and_hasher(capacity_hint(&table), S::default());
+            pairs_from_lua(&table, lua, map)
+        } else {
+            Err(Error::FromLuaConversionError {
+                from: value.type_name(),
+                to: Self::type_name(),
+                message: Some("expected table".to_string()),
+            })
+        }
+    }
+}
+
+impl<K: Ord + IntoLua, V: IntoLua> IntoLua for BTreeMap<K, V> {
+    #[inline]
+    fn into_lua(self, lua: &Lua) -> Result<Value> {
+        Ok(Value::Table(lua.create_table_from(self)?))
+    }
+}
+
+impl<K: Ord + FromLua, V: FromLua> FromLua for BTreeMap<K, V> {
+    #[inline]
+    fn from_lua(value: Value, lua: &Lua) -> Result<Self> {
+        if let Value::Table(table) = value {
+            pairs_from_lua(&table, lua, BTreeMap::new())
+        } else {
+            Err(Error::FromLuaConversionError {
+                from: value.type_name(),
+                to: Self::type_name(),
+                message: Some("expected table".to_string()),
+            })
+        }
+    }
+}
+
+/// Converts the pairs into a table, using the first element of each pair as the key.
+///
+/// If the same key appears more than once, the last pair wins.
+impl<K: IntoLua, V: IntoLua> IntoLua for Vec<(K, V)> {
+    #[inline]
+    fn into_lua(self, lua: &Lua) -> Result<Value> {
+        Ok(Value::Table(lua.create_table_from(self)?))
+    }
+}
+
+/// Collects all key-value pairs of a table.
+///
+/// Unlike map types, every entry is kept, even if several Lua keys convert to the same `K`.
+/// The order of pairs follows the table traversal order, which is unspecified in Lua.
+impl<K: FromLua, V: FromLua> FromLua for Vec<(K, V)> {
+    #[inline]
+    fn from_lua(value: Value, lua: &Lua) -> Result<Self> {
+        if let Value::Table(table) = value {
+            pairs_from_lua(&table, lua, Vec::new())
+        } else {
+            Err(Error::FromLuaConversionError {
+                from: value.type_name(),
+                to: Self::type_name(),
+                message: Some("expected table".to_string()),
+            })
+        }
+    }
+}
+
+impl<T: Eq + Hash + IntoLua, S: BuildHasher> IntoLua for HashSet<T, S> {
+    #[inline]
+    fn into_lua(self, lua: &Lua) -> Result<Value> {
+        Ok(Value::Table(
+            lua.create_table_from(self.into_iter().map(|val| (val, true)))?,
+        ))
+    }
+}
+
+impl<T: Eq + Hash + FromLua, S: BuildHasher + Default> FromLua for HashSet<T, S> {
+    #[inline]
+    fn from_lua(value: Value, lua: &Lua) -> Result<Self> {
+        match value {
+            Value::Table(table) if table.raw_len() > 0 => {
+                let set = HashSet::with_capacity_and_hasher(capacity_hint(&table), S::default());
+                sequence_from_lua(&table, set)
+            }
+            Value::Table(table) => keys_from_lua(&table, lua),
+            _ => Err(Error::FromLuaConversionError {
+                from: value.type_name(),
+                to: Self::type_name(),
+                message: Some("expected table".to_string()),
+            }),
+        }
+    }
+}
+
+impl<T: Ord + IntoLua> IntoLua for BTreeSet<T> {
+    #[inline]
+    fn into_lua(self, lua: &Lua) -> Result<Value> {
+        Ok(Value::Table(
+            lua.create_table_from(self.into_iter().map(|val| (val, true)))?,
+        ))
+    }
+}
+
+impl<T: Ord + FromLua> FromLua for BTreeSet<T> {
+    #[inline]
+    fn from_lua(value: Value, lua: &Lua) -> Result<Self> {
+        match value {
+            Value::Table(table) if table.raw_len() > 0 => sequence_from_lua(&table, BTreeSet::new()),
+            Value::Table(table) => keys_from_lua(&table, lua),
+            _ => Err(Error::FromLuaConversionError {
+                from: value.type_name(),
+                to: Self::type_name(),
+                message: Some("expected table".to_string()),
+            }),
+        }
+    }
+}
+
+/// Converts the heap into a sequence.
+///
+/// The elements are in the internal heap order, which is arbitrary and not sorted.
+impl<T: IntoLua> IntoLua for BinaryHeap<T> {
+    #[inline]
+    fn into_lua(self, lua: &Lua) -> Result<Value> {
+        Ok(Value::Table(lua.create_sequence_from(self)?))
+    }
+}
+
+impl<T: Ord + FromLua> FromLua for BinaryHeap<T> {
+    #[inline]
+    fn from_lua(value: Value, _: &Lua) -> Result<Self> {
+        match value {
+            Value::Table(table) => {
+                let heap = BinaryHeap::with_capacity(capacity_hint(&table));
+                sequence_from_lua(&table, heap)
+            }
+            _ => Err(Error::FromLuaConversionError {
+                from: value.type_name(),
+                to: Self::type_name(),
+                message: Some("expected table".to_string()),
+            }),
+        }
+    }
+}
+
+/// Upper bound for the capacity reserved up front when converting a table.
+///
+/// The table length is controlled by the script and a handful of carefully chosen hash keys can
+/// make it arbitrarily large, so it is only trusted up to this limit.
+const MAX_CAPACITY_HINT: usize = 1 << 16;
+
+#[inline]
+fn capacity_hint(table: &Table) -> usize {
+    table.raw_len().min(MAX_CAPACITY_HINT)
+}
+
+/// Adds the table sequence to `coll`, reporting the index and a preview of the element that failed.
+fn sequence_from_lua<T: FromLua, C: Extend<T>>(table: &Table, mut coll: C) -> Result<C> {
+    for (i, res) in table.sequence_values().enumerate() {
+        let v = res.map_err(|err| {
+            let value = table.raw_get(i + 1).unwrap_or(Nil);
+            conversion_error_at(format_args!("element #{}", i + 1), &value, err)
+        })?;
+        coll.extend([v]);
+    }
+    Ok(coll)
+}
+
+/// Adds the table pairs to `map`, reporting the key (and a preview of the value) that failed.
+fn pairs_from_lua<K: FromLua, V: FromLua, C: Extend<(K, V)>>(
+    table: &Table,
+    lua: &Lua,
+    mut map: C,
+) -> Result<C> {
+    for res in table.pairs::<Value, Value>() {
+        let (key, value) = res?;
+        let v = V::from_lua(value, lua).map_err(|err| {
+            let value = table.raw_get(&key).unwrap_or(Nil);
+            let key = value_preview(&key);
+            conversion_error_at(format_args!("value at key {key}"), &value, err)
+        })?;
+        let k = K::from_lua(key.clone(), lua)
+            .map_err(|err| conversion_error_at(format_args!("key"), &key, err))?;
+        map.extend([(k, v)]);
+    }
+    Ok(map)
+}
+
+/// Collects the table keys, reporting the key that failed.
+fn keys_from_lua<T: FromLua, C: FromIterator<T>>(table: &Table, lua: &Lua) -> Result<C> {
+    (table.pairs::<Value, Value>())
+        .map(|res| {
+            let (key, _) = res?;
+            T::from_lua(key.clone(), lua).map_err(|err| conversion_error_at(format_args!("key"), &key, err))
+        })
+        .collect()
+}
+
+/// Adds the location of a failed element (and a preview of its value) to the conversion error.
+fn conversion_error_at(at: fmt::Arguments, value: &Value, err: Error) -> Error {
+    err.context(format!("bad {at} ({})", value_preview(value)))
+}
+
+/// Returns a short preview of the value for error messages.
+///
+/// Strings are quoted and truncated, other non-scalar values are represented by their type name.
+fn value_preview(value: &Value) -> StdString {
+    const MAX_CHARS: usize = 32;
+    match value {
+        Value::Nil => "nil".to_string(),
+        Value::Boolean(b) => b.to_string(),
+        Value::Integer(i) => i.to_string(),
+        Value::Number(n) => n.to_string(),
+        Value::String(s) => {
+            let s = s.to_string_lossy();
+            match s.char_indices().nth(MAX_CHARS) {
+                Some((end, _)) => format!("{:?}...", &s[..end]),
+                None => format!("{s:?}"),
+            }
+        }
+        _ => value.type_name().to_string(),
+    }
+}
+
+/// Converts the range into a table `{ start = .., ["end"] = .. }`.
+///
+/// The bounds are passed verbatim, without adjusting them for the 1-based indexing in Lua.
+impl<T: IntoLua> IntoLua for Range<T> {
+    fn into_lua(self, lua: &Lua) -> Result<Value> {
+        let table = lua.create_table_with_capacity(0, 2)?;
+        table.raw_set("start", self.start)?;
+        table.raw_set("end", self.end)?;
+        Ok(Value::Table(table))
+    }
+}
+
+/// Reads a table with `start` and `end` fields.
+///
+/// The table must not have `inclusive = true`, as the end would be off by one. If present, the
+/// `inclusive` field must be a boolean.
+impl<T: FromLua> FromLua for Range<T> {
+    fn from_lua(value: Value, lua: &Lua) -> Result<Self> {
+        let (start, end) = range_bounds_from_lua(value, lua, false, "Range")?;
+        Ok(start..end)
+    }
+}
+
+/// Converts the range into a table `{ start = .., ["end"] = .., inclusive = true }`.
+///
+/// The bounds are passed verbatim, without adjusting them for the 1-based indexing in Lua.
+impl<T: IntoLua> IntoLua for RangeInclusive<T> {
+    fn into_lua(self, lua: &Lua) -> Result<Value> {
+        let (start, end) = self.into_inner();
+        let table = lua.create_table_with_capacity(0, 3)?;
+        table.raw_set("start", start)?;
+        table.raw_set("end", end)?;
+        table.raw_set("inclusive", true)?;
+        Ok(Value::Table(table))
+    }
+}
+
+/// Reads a table with `start` and `end` fields.
+///
+/// The `inclusive` field is not required, but the table must not have `inclusive = false`. If
+/// present, it must be a boolean.
+impl<T: FromLua> FromLua for RangeInclusive<T> {
+    fn from_lua(value: Value, lua: &Lua) -> Result<Self> {
+        let (start, end) = range_bounds_from_lua(value, lua, true, "RangeInclusive")?;
+        Ok(start..=end)
+    }
+}
+
+fn range_bounds_from_lua<T: FromLua>(value: Value, lua: &Lua, inclusive: bool, to: &str) -> Result<(T, T)> {
+    let conv_err = |from, message: StdString| Error::FromLuaConversionError {
+        from,
+        to: to.to_string(),
+        message: Some(message),
+    };
+    let table = match value {
+        Value::Table(table) => table,
+        _ => return Err(conv_err(value.type_name(), "expected table".to_string())),
+    };
+    match table.raw_get::<Option<StrictBool>>("inclusive")? {
+        Some(StrictBool(true)) if !inclusive => {
+            return Err(conv_err("table", "expected exclusive range".to_string()))
+        }
+        Some(StrictBool(false)) if inclusive => {
+            return Err(conv_err("table", "expected inclusive range".to_string()))
+        }
+        _ => {}
+    }
+    let get = |key| match table.raw_get::<Value>(key)? {
+        Value::Nil => Err(conv_err("table", format!("missing field `{key}`"))),
+        value => T::from_lua(value, lua),
+    };
+    Ok((get("start")?, get("end")?))
+}
+
+/// Converts the bound into a table `{ kind = "included" | "excluded" | "unbounded", value = .. }`.
+///
+/// The `value` field is omitted for [`Bound::Unbounded`].
+impl<T: IntoLua> IntoLua for Bound<T> {
+    fn into_lua(self, lua: &Lua) -> Result<Value> {
+        let table = lua.create_table_with_capacity(0, 2)?;
+        match self {
+            Bound::Included(value) => {
+                table.raw_set("kind", "included")?;
+                table.raw_set("value", value)?;
+            }
+            Bound::Excluded(value) => {
+                table.raw_set("kind", "excluded")?;
+                table.raw_set("value", value)?;
+            }
+            Bound::Unbounded => table.raw_set("kind", "unbounded")?,
+        }
+        Ok(Value::Table(table))
+    }
+}
+
+/// Reads a table with a `kind` field and, unless the bound is unbounded, a `value` field.
+impl<T: FromLua> FromLua for Bound<T> {
+    fn from_lua(value: Value, lua: &Lua) -> Result<Self> {
+        let conv_err = |from, message: StdString| Error::FromLuaConversionError {
+            from,
+            to: "Bound".to_string(),
+            message: Some(message),
+        };
+        let table = match value {
+            Value::Table(table) => table,
+            _ => return Err(conv_err(value.type_name(), "expected table".to_string())),
+        };
+        let get_value = || match table.raw_get::<Value>("value")? {
+            Value::Nil => Err(conv_err("table", "missing field `value`".to_string())),
+            value => T::from_lua(value, lua),
+        };
+        let kind = match table.raw_get::<Value>("kind")? {
+            Value::Nil => return Err(conv_err("table", "missing field `kind`".to_string())),
+            kind => StdString::from_lua(kind, lua)?,
+        };
+        match kind.as_str() {
+            "included" => Ok(Bound::Included(get_value()?)),
+            "excluded" => Ok(Bound::Excluded(get_value()?)),
+            "unbounded" => Ok(Bound::Unbounded),
+            _ => Err(conv_err("table", format!("unknown bound kind `{kind}`"))),
+        }
+    }
+}
+
+impl<T: IntoLua> IntoLua for Reverse<T> {
+    #[inline]
+    fn into_lua(self, lua: &Lua) -> Result<Value> {
+        self.0.into_lua(lua)
+    }
+
+    #[inline]
+    unsafe fn push_into_stack(self, lua: &RawLua) -> Result<()> {
+        self.0.push_into_stack(lua)
+    }
+}
+
+impl<T: FromLua> FromLua for Reverse<T> {
+    #[inline]
+    fn from_lua(value: Value, lua: &Lua) -> Result<Self> {
+        T::from_lua(value, lua).map(Reverse)
+    }
+
+    #[inline]
+    unsafe fn from_stack(idx: c_int, lua: &RawLua) -> Result<Self> {
+        T::from_stack(idx, lua).map(Reverse)
+    }
+}
+
+/// Converts the contained value.
+///
+/// Lua receives a snapshot of the value, later changes to the cell are not visible in Lua.
+impl<T: IntoLua> IntoLua for Cell<T> {
+    #[inline]
+    fn into_lua(self, lua: &Lua) -> Result<Value> {
+        self.into_inner().into_lua(lua)
+    }
+}
+
+/// Converts a copy of the contained value.
+///
+/// Lua receives a snapshot of the value, later changes to the cell are not visible in Lua.
+impl<T: IntoLua + Copy> IntoLua for &Cell<T> {
+    #[inline]
+    fn into_lua(self, lua: &Lua) -> Result<Value> {
+        self.get().into_lua(lua)
+    }
+}
+
+/// Wraps the converted value in a new cell, which is not shared with Lua.
+impl<T: FromLua> FromLua for Cell<T> {
+    #[inline]
+    fn from_lua(value: Value, lua: &Lua) -> Result<Self> {
+        T::from_lua(value, lua).map(Cell::new)
+    }
+}
+
+/// Converts the contained value.
+///
+/// Lua receives a snapshot of the value, later changes to the cell are not visible in Lua.
+impl<T: IntoLua> IntoLua for RefCell<T> {
+    #[inline]
+    fn into_lua(self, lua: &Lua) -> Result<Value> {
+        self.into_inner().into_lua(lua)
+    }
+}
+
+/// Converts a clone of the contained value.
+///
+/// Lua receives a snapshot of the value, later changes to the cell are not visible in Lua. Fails
+/// if the cell is currently mutably borrowed.
+impl<T: IntoLua + Clone> IntoLua for &RefCell<T> {
+    #[inline]
+    fn into_lua(self, lua: &Lua) -> Result<Value> {
+        let value = self.try_borrow().map_err(|_| Error::ToLuaConversionError {
+            from: Self::type_name(),
+            to: "value",
+            message: Some("already mutably borrowed".to_string()),
+        })?;
+        value.clone().into_lua(lua)
+    }
+}
+
+/// Wraps the converted value in a new cell, which is not shared with Lua.
+impl<T: FromLua> FromLua for RefCell<T> {
+    #[inline]
+    fn from_lua(value: Value, lua: &Lua) -> Result<Self> {
+        T::from_lua(value, lua).map(RefCell::new)
+    }
+}
+
+/// Converts the marker into `nil`.
+impl<T: ?Sized> IntoLua for PhantomData<T> {
+    #[inline]
+    fn into_lua(self, _: &Lua) -> Result<Value> {
+        Ok(Nil)
+    }
+}
+
+/// Accepts `nil` only.
+impl<T: ?Sized> FromLua for PhantomData<T> {
+    #[inline]
+    fn from_lua(value: Value, _: &Lua) -> Result<Self> {
+        match value {
+            Value::Nil => Ok(PhantomData),
+            _ => Err(Error::FromLuaConversionError {
+                from: value.type_name(),
+                to: "PhantomData".to_string(),
+                message: Some("expected nil".to_string()),
+            }),
+        }
+    }
+}
+
+/// Allows using [`Infallible`] in generic code that requires [`IntoLua`]. Since the type has no
+/// values, the conversion can never be called.
+impl IntoLua for Infallible {
+    #[inline]
+    fn into_lua(self, _: &Lua) -> Result<Value> {
+        match self {}
+    }
+}
+
+impl<T: IntoLua> IntoLua for Option<T> {
+    #[inline]
+    fn into_lua(self, lua: &Lua) -> Result<Value> {
+        match self {
+            Some(val) => val.into_lua(lua),
+            None => Ok(Nil),
+        }
+    }
+
+    #[inline]
+    unsafe fn push_into_stack(self, lua: &RawLua) -> Result<()> {
         match self {
             Some(val) => val.push_into_stack(lua)?,
             None => ffi::lua_pushnil(lua.state()),
@@ -1173,6 +2080,280 @@ impl<T: FromLua> FromLua for Option<T> {
     }
 }
 
+impl<T: IntoLua> IntoLua for Wrapping<T> {
+    #[inline]
+    fn into_lua(self, lua: &Lua) -> Result<Value> {
+        self.0.into_lua(lua)
+    }
+
+    #[inline]
+    unsafe fn push_into_stack(self, lua: &RawLua) -> Result<()> {
+        self.0.push_into_stack(lua)
+    }
+}
+
+impl<T: FromLua> FromLua for Wrapping<T> {
+    #[inline]
+    fn from_lua(value: Value, lua: &Lua) -> Result<Self> {
+        T::from_lua(value, lua).map(Wrapping)
+    }
+
+    #[inline]
+    fn from_lua_arg(arg: Value, i: usize, to: Option<&str>, lua: &Lua) -> Result<Self> {
+        T::from_lua_arg(arg, i, to, lua).map(Wrapping)
+    }
+
+    #[inline]
+    unsafe fn from_stack(idx: c_int, lua: &RawLua) -> Result<Self> {
+        T::from_stack(idx, lua).map(Wrapping)
+    }
+
+    #[inline]
+    unsafe fn from_stack_arg(idx: c_int, i: usize, to: Option<&str>, lua: &RawLua) -> Result<Self> {
+        T::from_stack_arg(idx, i, to, lua).map(Wrapping)
+    }
+}
+
+/// Converts a [`Duration`] into a Lua number of seconds (with a fractional part).
+///
+/// Very long durations may lose sub-second precision, as Lua numbers are `f64`.
+impl IntoLua for Duration {
+    #[inline]
+    fn into_lua(self, _: &Lua) -> Result<Value> {
+        Ok(Value::Number(self.as_secs_f64()))
+    }
+}
+
+/// Converts a non-negative Lua number of seconds into a [`Duration`].
+///
+/// Integers are converted exactly. Fractional numbers are rounded to the nearest nanosecond.
+impl FromLua for Duration {
+    fn from_lua(value: Value, lua: &Lua) -> Result<Self> {
+        let ty = value.type_name();
+        let conv_err = |message: StdString| Error::FromLuaConversionError {
+            from: ty,
+            to: "Duration".to_string(),
+            message: Some(message),
+        };
+        match value {
+            Value::Integer(i) if i < 0 => Err(conv_err("duration cannot be negative".to_string())),
+            Value::Integer(i) => Ok(Duration::from_secs(i as u64)),
+            value => {
+                let secs = lua
+                    .coerce_number(value)?
+                    .ok_or_else(|| conv_err("expected number or string coercible to number".to_string()))?;
+                if secs < 0.0 {
+                    return Err(conv_err("duration cannot be negative".to_string()));
+                }
+                Duration::try_from_secs_f64(secs).map_err(|err| conv_err(err.to_string()))
+            }
+        }
+    }
+}
+
+/// Converts a [`SystemTime`] into a Lua number of seconds since the Unix epoch (with a fractional
+/// part).
+///
+/// Times before the epoch are represented as negative numbers.
+impl IntoLua for SystemTime {
+    #[inline]
+    fn into_lua(self, _: &Lua) -> Result<Value> {
+        let secs = match self.duration_since(UNIX_EPOCH) {
+            Ok(elapsed) => elapsed.as_secs_f64(),
+            Err(err) => -err.duration().as_secs_f64(),
+        };
+        Ok(Value::Number(secs))
+    }
+}
+
+/// Converts a Lua number of seconds since the Unix epoch into a [`SystemTime`].
+///
+/// Negative numbers are supported and represent times before the epoch.
+impl FromLua for SystemTime {
+    fn from_lua(value: Value, lua: &Lua) -> Result<Self> {
+        let ty = value.type_name();
+        let conv_err = |message: StdString| Error::FromLuaConversionError {
+            from: ty,
+            to: "SystemTime".to_string(),
+            message: Some(message),
+        };
+        let secs = lua
+            .coerce_number(value)?
+            .ok_or_else(|| conv_err("expected number or string coercible to number".to_string()))?;
+        let offset = Duration::try_from_secs_f64(secs.abs()).map_err(|err| conv_err(err.to_string()))?;
+        let time = if secs < 0.0 {
+            UNIX_EPOCH.checked_sub(offset)
+        } else {
+            UNIX_EPOCH.checked_add(offset)
+        };
+        time.ok_or_else(|| conv_err(format!("timestamp {secs} is out of range")))
+    }
+}
+
+/// Converts a UTC date and time into an RFC 3339 string (eg. `"2024-01-02T03:04:05Z"`).
+#[cfg(feature = "chrono")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+impl IntoLua for chrono::DateTime<chrono::Utc> {
+    #[inline]
+    fn into_lua(self, lua: &Lua) -> Result<Value> {
+        let s = self.to_rfc3339_opts(chrono::SecondsFormat::AutoSi, true);
+        Ok(Value::String(lua.create_string(s)?))
+    }
+}
+
+/// Converts an RFC 3339 string or an integer Unix timestamp (in seconds) into a UTC date and time.
+#[cfg(feature = "chrono")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+impl FromLua for chrono::DateTime<chrono::Utc> {
+    fn from_lua(value: Value, _: &Lua) -> Result<Self> {
+        use chrono::{DateTime, TimeZone, Utc};
+
+        let ty = value.type_name();
+        let conv_err = |message: StdString| Error::FromLuaConversionError {
+            from: ty,
+            to: "DateTime<Utc>".to_string(),
+            message: Some(message),
+        };
+        match value {
+            Value::String(s) => DateTime::parse_from_rfc3339(&s.to_str()?)
+                .map(|dt| dt.with_timezone(&Utc))
+                .map_err(|err| conv_err(format!("invalid RFC 3339 date and time: {err}"))),
+            #[allow(clippy::useless_conversion)]
+            Value::Integer(i) => Utc
+                .timestamp_opt(i.into(), 0)
+                .single()
+                .ok_or_else(|| conv_err(format!("timestamp {i} is out of range"))),
+            _ => Err(conv_err(
+                "expected RFC 3339 string or integer timestamp".to_string(),
+            )),
+        }
+    }
+}
+
+/// Converts a date and time without timezone into an RFC 3339 string without offset
+/// (eg. `"2024-01-02T03:04:05"`).
+#[cfg(feature = "chrono")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+impl IntoLua for chrono::NaiveDateTime {
+    #[inline]
+    fn into_lua(self, lua: &Lua) -> Result<Value> {
+        let s = self.format("%Y-%m-%dT%H:%M:%S%.f").to_string();
+        Ok(Value::String(lua.create_string(s)?))
+    }
+}
+
+#[cfg(feature = "chrono")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+impl FromLua for chrono::NaiveDateTime {
+    fn from_lua(value: Value, _: &Lua) -> Result<Self> {
+        let ty = value.type_name();
+        match value {
+            Value::String(s) => s.to_str()?.parse().map_err(|err| Error::FromLuaConversionError {
+                from: ty,
+                to: "NaiveDateTime".to_string(),
+                message: Some(format!("invalid date and time: {err}")),
+            }),
+            _ => Err(Error::FromLuaConversionError {
+                from: ty,
+                to: "NaiveDateTime".to_string(),
+                message: Some("expected string".to_string()),
+            }),
+        }
+    }
+}
+
+/// Converts a JSON value into a Lua value.
+///
+/// Objects become tables, arrays become sequences and `null` becomes `nil` (so `null` array
+/// elements leave holes in the resulting sequence). Numbers that fit into an integer are converted
+/// into Lua integers, other numbers into Lua floats. Numbers that overflow a float (possible with
+/// the `arbitrary_precision` feature of `serde_json`) result in an error.
+#[cfg(feature = "serde_json")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde_json")))]
+impl IntoLua for serde_json::Value {
+    fn into_lua(self, lua: &Lua) -> Result<Value> {
+        match self {
+            serde_json::Value::Null => Ok(Nil),
+            serde_json::Value::Bool(b) => Ok(Value::Boolean(b)),
+            serde_json::Value::Number(n) => match (n.as_i64(), n.as_u64()) {
+                (Some(i), _) => i.into_lua(lua),
+                (_, Some(u)) => u.into_lua(lua),
+                _ => match n.as_f64() {
+                    Some(f) => Ok(Value::Number(f)),
+                    // Only possible with the `arbitrary_precision` feature of `serde_json`
+                    None => Err(Error::ToLuaConversionError {
+                        from: "serde_json::Number".to_string(),
+                        to: "number",
+                        message: Some(format!("number {n} cannot be represented as a float")),
+                    }),
+                },
+            },
+            serde_json::Value::String(s) => Ok(Value::String(lua.create_string(s)?)),
+            serde_json::Value::Array(values) => Ok(Value::Table(lua.create_sequence_from(values)?)),
+            serde_json::Value::Object(map) => Ok(Value::Table(lua.create_table_from(map)?)),
+        }
+    }
+}
+
+/// Converts a Lua value into a JSON value.
+///
+/// Tables whose keys form a sequence `1..=n` become arrays, and any other table becomes an object
+/// with integer and float keys converted to strings. An empty table is converted into an empty
+/// array, because it's not possible to tell whether it was meant to be an array or an object.
+/// Lua integers and floats are converted into JSON integers and floats respectively.
+///
+/// Returns an error if the value contains a recursive table, a value that cannot be represented in
+/// JSON (eg. function or userdata), a non-finite float or a string that is not valid UTF-8.
+#[cfg(feature = "serde_json")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde_json")))]
+impl FromLua for serde_json::Value {
+    fn from_lua(value: Value, _: &Lua) -> Result<Self> {
+        let ty = value.type_name();
+        let conv_err = |message: StdString| Error::FromLuaConversionError {
+            from: ty,
+            to: "serde_json::Value".to_string(),
+            message: Some(message),
+        };
+
+        fn to_json(value: PlainValue) -> std::result::Result<serde_json::Value, StdString> {
+            Ok(match value {
+                PlainValue::Nil => serde_json::Value::Null,
+                PlainValue::Boolean(b) => serde_json::Value::Bool(b),
+                PlainValue::Integer(i) => serde_json::Value::from(i),
+                PlainValue::Number(n) => serde_json::Number::from_f64(n)
+                    .map(serde_json::Value::Number)
+                    .ok_or_else(|| format!("number {n} cannot be represented in JSON"))?,
+                PlainValue::String(s) => serde_json::Value::String(to_json_string(s)?),
+                PlainValue::Array(values) => serde_json::Value::Array(
+                    values
+                        .into_iter()
+                        .map(to_json)
+                        .collect::<std::result::Result<_, _>>()?,
+                ),
+                PlainValue::Map(pairs) => {
+                    let mut map = serde_json::Map::with_capacity(pairs.len());
+                    for (key, value) in pairs {
+                        let key = match key {
+                            PlainValue::String(s) => to_json_string(s)?,
+                            PlainValue::Integer(i) => i.to_string(),
+                            PlainValue::Number(n) => n.to_string(),
+                            key => return Err(format!("{} cannot be used as object key", key.type_name())),
+                        };
+                        map.insert(key, to_json(value)?);
+                    }
+                    serde_json::Value::Object(map)
+                }
+            })
+        }
+
+        fn to_json_string(s: Vec<u8>) -> std::result::Result<StdString, StdString> {
+            StdString::from_utf8(s).map_err(|err| format!("invalid utf-8 string: {err}"))
+        }
+
+        to_json(value.to_plain()?).map_err(conv_err)
+    }
+}
+
 impl<L: IntoLua, R: IntoLua> IntoLua for Either<L, R> {
     #[inline]
     fn into_lua(self, lua: &Lua) -> Result<Value> {
@@ -1191,6 +2372,9 @@ impl<L: IntoLua, R: IntoLua> IntoLua for Either<L, R> {
     }
 }
 
+/// Tries to convert the value into `L` first, then into `R`.
+///
+/// If both conversions fail, the error message includes both underlying errors.
 impl<L: FromLua, R: FromLua> FromLua for Either<L, R> {
     #[inline]
     fn from_lua(value: Value, lua: &Lua) -> Result<Self> {
@@ -1199,12 +2383,12 @@ impl<L: FromLua, R: FromLua> FromLua for Either<L, R> {
         match L::from_lua(value.clone(), lua) {
             Ok(l) => Ok(Either::Left(l)),
             // Try the right type
-            Err(_) => match R::from_lua(value, lua).map(Either::Right) {
+            Err(err_l) => match R::from_lua(value, lua).map(Either::Right) {
                 Ok(r) => Ok(r),
-                Err(_) => Err(Error::FromLuaConversionError {
+                Err(err_r) => Err(Error::FromLuaConversionError {
                     from: value_type_name,
                     to: Self::type_name(),
-                    message: None,
+                    message: Some(either_error_message::<L, R>(err_l, err_r)),
                 }),
             },
         }
@@ -1214,17 +2398,175 @@ impl<L: FromLua, R: FromLua> FromLua for Either<L, R> {
     unsafe fn from_stack(idx: c_int, lua: &RawLua) -> Result<Self> {
         match L::from_stack(idx, lua) {
             Ok(l) => Ok(Either::Left(l)),
-            Err(_) => match R::from_stack(idx, lua).map(Either::Right) {
+            Err(err_l) => match R::from_stack(idx, lua).map(Either::Right) {
                 Ok(r) => Ok(r),
-                Err(_) => {
+                Err(err_r) => {
                     let value_type_name = CStr::from_ptr(ffi::luaL_typename(lua.state(), idx));
                     Err(Error::FromLuaConversionError {
                         from: value_type_name.to_str().unwrap(),
                         to: Self::type_name(),
-                        message: None,
+                        message: Some(either_error_message::<L, R>(err_l, err_r)),
                     })
                 }
             },
         }
     }
 }
+
+fn either_error_message<L, R>(err_l: Error, err_r: Error) -> StdString {
+    format!("as {}: {err_l}; as {}: {err_r}", L::type_name(), R::type_name())
+}
+
+/// Converts a UUID into its hyphenated lowercase string form
+/// (eg. `"67e55044-10b1-426f-9247-bb680e5fe0c8"`).
+#[cfg(feature = "uuid")]
+#[cfg_attr(docsrs, doc(cfg(feature = "uuid")))]
+impl IntoLua for uuid::Uuid {
+    #[inline]
+    fn into_lua(self, lua: &Lua) -> Result<Value> {
+        let mut buf = [0; uuid::fmt::Hyphenated::LENGTH];
+        Ok(Value::String(
+            lua.create_string(self.hyphenated().encode_lower(&mut buf))?,
+        ))
+    }
+}
+
+/// Converts a Lua string into a UUID.
+///
+/// Any format accepted by [`uuid::Uuid::parse_str`] is supported. Alternatively, a 16-byte string
+/// is treated as the raw big-endian bytes of the UUID.
+#[cfg(feature = "uuid")]
+#[cfg_attr(docsrs, doc(cfg(feature = "uuid")))]
+impl FromLua for uuid::Uuid {
+    fn from_lua(value: Value, _: &Lua) -> Result<Self> {
+        let ty = value.type_name();
+        let conv_err = |message: StdString| Error::FromLuaConversionError {
+            from: ty,
+            to: "Uuid".to_string(),
+            message: Some(message),
+        };
+        let s = match value {
+            Value::String(s) => s,
+            _ => return Err(conv_err("expected string".to_string())),
+        };
+        let bytes = s.as_bytes();
+        if let Ok(raw) = <[u8; 16]>::try_from(&bytes[..]) {
+            return Ok(uuid::Uuid::from_bytes(raw));
+        }
+        let s = str::from_utf8(&bytes).map_err(|err| conv_err(format!("invalid uuid: {err}")))?;
+        uuid::Uuid::parse_str(s).map_err(|err| conv_err(format!("invalid uuid: {err}")))
+    }
+}
+
+/// Converts the bytes into a Lua string.
+///
+/// Lua strings are immutable and owned by Lua, so the data is copied once when creating the string.
+#[cfg(feature = "bytes")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bytes")))]
+impl IntoLua for bytes::Bytes {
+    #[inline]
+    fn into_lua(self, lua: &Lua) -> Result<Value> {
+        Ok(Value::String(lua.create_string(&self)?))
+    }
+
+    #[inline]
+    unsafe fn push_into_stack(self, lua: &RawLua) -> Result<()> {
+        push_bytes_into_stack(&*self, lua)
+    }
+}
+
+/// Converts the bytes into a Lua string.
+///
+/// Lua strings are immutable and owned by Lua, so the data is copied once when creating the string.
+#[cfg(feature = "bytes")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bytes")))]
+impl IntoLua for bytes::BytesMut {
+    #[inline]
+    fn into_lua(self, lua: &Lua) -> Result<Value> {
+        Ok(Value::String(lua.create_string(&self)?))
+    }
+
+    #[inline]
+    unsafe fn push_into_stack(self, lua: &RawLua) -> Result<()> {
+        push_bytes_into_stack(&*self, lua)
+    }
+}
+
+/// Converts a Lua string (or Luau buffer) into bytes, copying the data.
+///
+/// Numbers are coerced to strings, same as for [`BString`].
+#[cfg(feature = "bytes")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bytes")))]
+impl FromLua for bytes::Bytes {
+    #[inline]
+    fn from_lua(value: Value, lua: &Lua) -> Result<Self> {
+        Ok(bytes::BytesMut::from_lua(value, lua)?.freeze())
+    }
+}
+
+#[cfg(feature = "bytes")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bytes")))]
+impl FromLua for bytes::BytesMut {
+    fn from_lua(value: Value, lua: &Lua) -> Result<Self> {
+        let ty = value.type_name();
+        match value {
+            Value::String(s) => Ok(Self::from(&*s.as_bytes())),
+            #[cfg(feature = "luau")]
+            Value::Buffer(buf) => unsafe { Ok(Self::from(buf.as_slice())) },
+            _ => Ok(Self::from(
+                &*lua
+                    .coerce_string(value)?
+                    .ok_or_else(|| Error::FromLuaConversionError {
+                        from: ty,
+                        to: "BytesMut".to_string(),
+                        message: Some("expected string or number".to_string()),
+                    })?
+                    .as_bytes(),
+            )),
+        }
+    }
+}
+
+/// Converts the decimal into its canonical string representation to preserve precision.
+#[cfg(feature = "rust_decimal")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rust_decimal")))]
+impl IntoLua for rust_decimal::Decimal {
+    #[inline]
+    fn into_lua(self, lua: &Lua) -> Result<Value> {
+        Ok(Value::String(lua.create_string(self.to_string())?))
+    }
+}
+
+/// Parses a Lua string into a decimal.
+///
+/// Lua numbers are accepted only when they hold an exact integer value, since converting a
+/// fractional `f64` would silently bring its rounding error into the decimal. Pass such values as
+/// strings instead.
+#[cfg(feature = "rust_decimal")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rust_decimal")))]
+impl FromLua for rust_decimal::Decimal {
+    fn from_lua(value: Value, _: &Lua) -> Result<Self> {
+        let ty = value.type_name();
+        let conv_err = |message: StdString| Error::FromLuaConversionError {
+            from: ty,
+            to: "Decimal".to_string(),
+            message: Some(message),
+        };
+        match value {
+            Value::String(s) => {
+                let s = s.to_str()?;
+                s.parse()
+                    .map_err(|err| conv_err(format!("invalid decimal: {err}")))
+            }
+            #[allow(clippy::useless_conversion)]
+            Value::Integer(i) => Ok(i64::from(i).into()),
+            Value::Number(n) if n.fract() == 0.0 && n >= i64::MIN as f64 && n < i64::MAX as f64 => {
+                Ok((n as i64).into())
+            }
+            Value::Number(_) => Err(conv_err(
+                "number is not an exact integer, pass decimal as a string".to_string(),
+            )),
+            _ => Err(conv_err("expected string or number".to_string())),
+        }
+    }
+}
@@ -361,7 +361,10 @@ impl Error {
         T: StdError + 'static,
     {
         match self {
-            Error::ExternalError(err) => err.downcast_ref(),
+            Error::ExternalError(err) => match err.downcast_ref::<SourceChain>() {
+                Some(SourceChain(err)) => err.downcast_ref(),
+                None => err.downcast_ref(),
+            },
             Error::WithContext { cause, .. } => Self::downcast_ref(cause),
             _ => None,
         }
@@ -494,6 +497,45 @@ impl From<Utf8Error> for Error {
     }
 }
 
+/// Wraps a boxed error object.
+///
+/// Unlike [`Error::external`], the sources of the error are included into the message
+/// (`"outer: inner"`), so that nested causes are not lost when the error is reported to Lua.
+/// The original error can still be accessed using [`Error::downcast_ref`].
+impl From<Box<dyn StdError + Send + Sync>> for Error {
+    fn from(err: Box<dyn StdError + Send + Sync>) -> Self {
+        let err = match err.downcast::<Error>() {
+            Ok(err) => return *err,
+            Err(err) => err,
+        };
+        match err.source() {
+            Some(_) => Error::external(SourceChain(err)),
+            None => Error::external(err as Box<DynStdError>),
+        }
+    }
+}
+
+/// An error whose `Display` implementation includes all of its sources.
+///
+/// The sources are not exposed through [`StdError::source`], otherwise error reporters walking
+/// the chain would print them twice.
+#[derive(Debug)]
+struct SourceChain(Box<dyn StdError + Send + Sync>);
+
+impl fmt::Display for SourceChain {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "{}", self.0)?;
+        let mut source = self.0.source();
+        while let Some(err) = source {
+            write!(fmt, ": {err}")?;
+            source = err.source();
+        }
+        Ok(())
+    }
+}
+
+impl StdError for SourceChain {}
+
 #[cfg(feature = "serialize")]
 impl serde::ser::Error for Error {
     fn custom<T: fmt::Display>(msg: T) -> Self {
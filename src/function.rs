@@ -1,6 +1,6 @@
 use std::cell::RefCell;
 use std::os::raw::{c_int, c_void};
-use std::{mem, ptr, slice};
+use std::{fmt, mem, ptr, slice};
 
 use crate::error::{Error, Result};
 use crate::state::Lua;
@@ -256,6 +256,33 @@ impl Function {
         .call((self, args_wrapper))
     }
 
+    /// Converts this function into a Rust closure with fixed argument and return types.
+    ///
+    /// The closure owns the function handle, so it can be stored and called repeatedly without
+    /// repeating the type annotations at each call site. Like the handle itself, the closure does
+    /// not keep the Lua instance alive, and calling it after the instance is dropped will panic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mlua::{Function, Lua, Result};
+    /// # fn main() -> Result<()> {
+    /// # let lua = Lua::new();
+    /// let add: Function = lua.load("function(a, b) return a + b end").eval()?;
+    /// let add = add.into_closure::<(i64, i64), i64>();
+    /// assert_eq!(add((1, 2))?, 3);
+    /// assert_eq!(add((3, 4))?, 7);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn into_closure<A, R>(self) -> impl Fn(A) -> Result<R>
+    where
+        A: IntoLuaMulti,
+        R: FromLuaMulti,
+    {
+        move |args| self.call(args)
+    }
+
     /// Returns the environment of the Lua function.
     ///
     /// By default Lua functions shares a global environment.
@@ -640,6 +667,58 @@ impl IntoLua for WrappedAsyncFunction {
     }
 }
 
+/// A Rust function or closure that is turned into a Lua function on conversion.
+///
+/// Unlike [`Lua::create_function`], creating a `LuaFn` does not require a [`Lua`] instance. The
+/// Lua function is created when the value is converted using [`IntoLua`], which makes it possible
+/// to store callbacks in structs and convert them generically later on. The closure has the same
+/// signature as the one passed to [`Lua::create_function`].
+///
+/// Each conversion consumes the `LuaFn`, so it can be converted only once.
+///
+/// # Examples
+///
+/// ```
+/// # use mlua::{Lua, LuaFn, Result};
+/// # fn main() -> Result<()> {
+/// let double = LuaFn::new(|_, n: i64| Ok(n * 2));
+///
+/// let lua = Lua::new();
+/// lua.globals().set("double", double)?;
+/// assert_eq!(lua.load("double(21)").eval::<i64>()?, 42);
+/// # Ok(())
+/// # }
+/// ```
+pub struct LuaFn(Callback);
+
+impl LuaFn {
+    /// Wraps a Rust function or closure.
+    pub fn new<F, A, R>(func: F) -> Self
+    where
+        F: Fn(&Lua, A) -> Result<R> + MaybeSend + 'static,
+        A: FromLuaMulti,
+        R: IntoLuaMulti,
+    {
+        LuaFn(Box::new(move |rawlua, nargs| unsafe {
+            let args = A::from_stack_args(nargs, 1, None, rawlua)?;
+            func(rawlua.lua(), args)?.push_into_stack_multi(rawlua)
+        }))
+    }
+}
+
+impl fmt::Debug for LuaFn {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("LuaFn(..)")
+    }
+}
+
+impl IntoLua for LuaFn {
+    #[inline]
+    fn into_lua(self, lua: &Lua) -> Result<Value> {
+        lua.lock().create_callback(self.0).map(Value::Function)
+    }
+}
+
 impl LuaType for Function {
     const TYPE_ID: c_int = ffi::LUA_TFUNCTION;
 }
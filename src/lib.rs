@@ -95,31 +95,39 @@ mod vector;
 
 pub mod prelude;
 
-pub use bstr::BString;
+pub use bstr::{BStr, BString};
 pub use ffi::{self, lua_CFunction, lua_State};
 
 pub use crate::chunk::{AsChunk, Chunk, ChunkMode};
 pub use crate::error::{Error, ErrorContext, ExternalError, ExternalResult, Result};
-pub use crate::function::{Function, FunctionInfo};
+pub use crate::function::{Function, FunctionInfo, LuaFn};
 pub use crate::hook::{Debug, DebugEvent, DebugNames, DebugSource, DebugStack};
-pub use crate::multi::{MultiValue, Variadic};
+pub use crate::multi::{
+    Args, ArgsReader, Checked, Counted, ErrFirst, Flatten, FromArgs, IgnoreRest, IntoLuaTupleItem, KvPairs,
+    MultiValue, MultiValueBuilder, NamedTuple, NonEmptyVariadic, Present, RaiseOnErr, RestArgs, SpreadKv,
+    SpreadOptional, TableTuple, Unpacked, Variadic,
+};
 pub use crate::scope::Scope;
 pub use crate::state::{GCMode, Lua, LuaOptions};
 pub use crate::stdlib::StdLib;
 pub use crate::string::{BorrowedBytes, BorrowedStr, String};
-pub use crate::table::{Table, TablePairs, TableSequence};
+pub use crate::table::{Table, TableBuilder, TableKind, TablePairs, TableSequence};
 pub use crate::thread::{Thread, ThreadStatus};
 pub use crate::traits::{
     FromLua, FromLuaMulti, IntoLua, IntoLuaMulti, LuaNativeFn, LuaNativeFnMut, ObjectLike,
 };
 pub use crate::types::{
-    AppDataRef, AppDataRefMut, Either, Integer, LightUserData, MaybeSend, Number, RegistryKey, VmState,
+    AppDataRef, AppDataRefMut, BasisPoints, Color, Deadline, Digest, DurationRich, Either, FiniteFloat,
+    FlagTable, Flags, Fraction01, Hex16, Index, Integer, InternedKeys, LatLon, Lazy, LightUserData, LuaNum,
+    MaybeSend, Number, NumberOrString, Quantity, RangeQuery, Rate, Rect, RegistryKey, SaturatingDuration,
+    SeqIter, Sequence, Snapshot, SparseArray, SplitDuration, StrictBool, StrictInt, StrictString,
+    StringInterner, TaggedBlob, VmState, WrappingInt,
 };
 pub use crate::userdata::{
     AnyUserData, MetaMethod, UserData, UserDataFields, UserDataMetatable, UserDataMethods, UserDataRef,
     UserDataRefMut, UserDataRegistry,
 };
-pub use crate::value::{Nil, Value};
+pub use crate::value::{Nil, PlainValue, Value};
 
 #[cfg(not(feature = "luau"))]
 pub use crate::hook::HookTriggers;
@@ -133,13 +141,23 @@ pub use crate::{
     vector::Vector,
 };
 
+#[cfg(feature = "rayon")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+pub use crate::types::ParVec;
+#[cfg(feature = "chrono")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+pub use crate::types::UnixTimestamp;
+
 #[cfg(feature = "async")]
 #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
 pub use crate::{thread::AsyncThread, traits::LuaNativeAsyncFn};
 
 #[cfg(feature = "serialize")]
 #[doc(inline)]
-pub use crate::serde::{de::Options as DeserializeOptions, ser::Options as SerializeOptions, LuaSerdeExt};
+pub use crate::serde::{
+    de::Options as DeserializeOptions, ser::Options as SerializeOptions, Deserialized, LuaSerdeExt,
+    Serialized,
+};
 
 #[cfg(feature = "serialize")]
 #[cfg_attr(docsrs, doc(cfg(feature = "serialize")))]
@@ -1,12 +1,18 @@
-use std::collections::{vec_deque, VecDeque};
+use std::collections::hash_map::RandomState;
+use std::collections::{vec_deque, HashMap, VecDeque};
+use std::hash::{BuildHasher, Hash};
 use std::iter::FromIterator;
-use std::mem;
+use std::marker::PhantomData;
 use std::ops::{Deref, DerefMut};
 use std::os::raw::c_int;
 use std::result::Result as StdResult;
+use std::string::String as StdString;
+use std::sync::Arc;
+use std::{fmt, mem};
 
-use crate::error::Result;
-use crate::state::{Lua, RawLua};
+use crate::error::{Error, ErrorContext as _, ExternalError, Result};
+use crate::state::{Lua, RawLua, WeakLua};
+use crate::table::Table;
 use crate::traits::{FromLua, FromLuaMulti, IntoLua, IntoLuaMulti};
 use crate::util::check_stack;
 use crate::value::{Nil, Value};
@@ -49,6 +55,110 @@ impl<E: IntoLua> IntoLuaMulti for StdResult<(), E> {
     }
 }
 
+/// A [`Result`] wrapper that is converted to [`MultiValue`] with the error first.
+///
+/// On success it returns `nil` followed by the value, and on error it returns the error followed
+/// by `nil`. This matches the convention of some Lua libraries, where the caller checks the first
+/// value for an error.
+///
+/// # Examples
+///
+/// ```
+/// # use mlua::{ErrFirst, Lua, Result};
+/// # fn main() -> Result<()> {
+/// let lua = Lua::new();
+/// let parse = lua.create_function(|_, s: String| {
+///     Ok(ErrFirst(s.parse::<i64>().map_err(|e| e.to_string())))
+/// })?;
+/// lua.globals().set("parse", parse)?;
+/// lua.load(r#"
+///     local err, n = parse("42")
+///     assert(err == nil and n == 42)
+///     err, n = parse("x")
+///     assert(err ~= nil and n == nil)
+/// "#).exec()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ErrFirst<T, E>(pub StdResult<T, E>);
+
+impl<T, E> From<StdResult<T, E>> for ErrFirst<T, E> {
+    #[inline]
+    fn from(res: StdResult<T, E>) -> Self {
+        ErrFirst(res)
+    }
+}
+
+impl<T: IntoLua, E: IntoLua> IntoLuaMulti for ErrFirst<T, E> {
+    #[inline]
+    fn into_lua_multi(self, lua: &Lua) -> Result<MultiValue> {
+        match self.0 {
+            Ok(val) => (Nil, val).into_lua_multi(lua),
+            Err(err) => (err, Nil).into_lua_multi(lua),
+        }
+    }
+
+    #[inline]
+    unsafe fn push_into_stack_multi(self, lua: &RawLua) -> Result<c_int> {
+        match self.0 {
+            Ok(val) => (Nil, val).push_into_stack_multi(lua),
+            Err(err) => (err, Nil).push_into_stack_multi(lua),
+        }
+    }
+}
+
+/// A [`Result`] wrapper that raises a Lua error on failure instead of returning `nil, err`.
+///
+/// On success the value is converted as usual. On error the conversion itself fails, so the
+/// error propagates to Lua as a regular error that can be caught with `pcall`.
+///
+/// # Examples
+///
+/// ```
+/// # use mlua::{Lua, RaiseOnErr, Result};
+/// # fn main() -> Result<()> {
+/// let lua = Lua::new();
+/// let parse = lua.create_function(|_, s: String| {
+///     Ok(RaiseOnErr(s.parse::<i64>()))
+/// })?;
+/// lua.globals().set("parse", parse)?;
+/// lua.load(r#"
+///     assert(parse("42") == 42)
+///     local ok, err = pcall(parse, "x")
+///     assert(not ok and tostring(err):find("invalid digit"))
+/// "#).exec()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RaiseOnErr<T, E>(pub StdResult<T, E>);
+
+impl<T, E> From<StdResult<T, E>> for RaiseOnErr<T, E> {
+    #[inline]
+    fn from(res: StdResult<T, E>) -> Self {
+        RaiseOnErr(res)
+    }
+}
+
+impl<T: IntoLuaMulti, E: ExternalError> IntoLuaMulti for RaiseOnErr<T, E> {
+    #[inline]
+    fn into_lua_multi(self, lua: &Lua) -> Result<MultiValue> {
+        match self.0 {
+            Ok(val) => val.into_lua_multi(lua),
+            Err(err) => Err(err.into_lua_err()),
+        }
+    }
+
+    #[inline]
+    unsafe fn push_into_stack_multi(self, lua: &RawLua) -> Result<c_int> {
+        match self.0 {
+            Ok(val) => val.push_into_stack_multi(lua),
+            Err(err) => Err(err.into_lua_err()),
+        }
+    }
+}
+
 impl<T: IntoLua> IntoLuaMulti for T {
     #[inline]
     fn into_lua_multi(self, lua: &Lua) -> Result<MultiValue> {
@@ -141,6 +251,26 @@ impl MultiValue {
         self.into()
     }
 
+    /// Consumes the `MultiValue` and converts every value into `T`.
+    ///
+    /// Stops at the first value that cannot be converted, returning an error that includes its
+    /// position (starting from 1).
+    pub fn into_typed<T: FromLua>(self, lua: &Lua) -> Result<Vec<T>> {
+        (self.0.into_iter().enumerate())
+            .map(|(i, value)| T::from_lua(value, lua).context(format!("value #{}", i + 1)))
+            .collect()
+    }
+
+    /// Converts a clone of the value at `index` (starting from 0) into `T`, leaving the
+    /// `MultiValue` untouched.
+    ///
+    /// If `index` is out of range, `nil` is converted instead, matching how Lua treats missing
+    /// return values.
+    pub fn get_typed<T: FromLua>(&self, index: usize, lua: &Lua) -> Result<T> {
+        let value = self.0.get(index).cloned().unwrap_or(Nil);
+        T::from_lua(value, lua).context(format!("value #{}", index + 1))
+    }
+
     #[inline]
     pub(crate) fn from_lua_iter<T: IntoLua>(lua: &Lua, iter: impl IntoIterator<Item = T>) -> Result<Self> {
         let iter = iter.into_iter();
@@ -211,6 +341,83 @@ impl FromLuaMulti for MultiValue {
     }
 }
 
+/// A helper to build a [`MultiValue`] using method chaining.
+///
+/// Values are converted using [`IntoLua`] as they are added, and can be inserted at either end.
+/// Errors are deferred until [`MultiValueBuilder::build`] is called. The builder can also be
+/// returned from a Rust callback or passed as function arguments directly.
+///
+/// # Examples
+///
+/// ```
+/// # use mlua::{Function, Lua, MultiValueBuilder, Result};
+/// # fn main() -> Result<()> {
+/// let lua = Lua::new();
+/// let concat = lua.load("function(...) return table.concat({...}, ',') end").eval::<Function>()?;
+/// let args = MultiValueBuilder::with_capacity(&lua, 3)
+///     .push_back("b")
+///     .push_back(3)
+///     .push_front("a")
+///     .build()?;
+/// assert_eq!(concat.call::<String>(args)?, "a,b,3");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct MultiValueBuilder<'a> {
+    lua: &'a Lua,
+    values: Result<MultiValue>,
+}
+
+impl<'a> MultiValueBuilder<'a> {
+    /// Creates a new builder for an empty `MultiValue`.
+    pub fn new(lua: &'a Lua) -> Self {
+        Self::with_capacity(lua, 0)
+    }
+
+    /// Creates a new builder with space for at least `capacity` values.
+    pub fn with_capacity(lua: &'a Lua, capacity: usize) -> Self {
+        MultiValueBuilder {
+            lua,
+            values: Ok(MultiValue::with_capacity(capacity)),
+        }
+    }
+
+    /// Appends a value to the end.
+    pub fn push_back(mut self, value: impl IntoLua) -> Self {
+        if let Ok(values) = &mut self.values {
+            match value.into_lua(self.lua) {
+                Ok(value) => values.push_back(value),
+                Err(err) => self.values = Err(err),
+            }
+        }
+        self
+    }
+
+    /// Prepends a value to the front.
+    pub fn push_front(mut self, value: impl IntoLua) -> Self {
+        if let Ok(values) = &mut self.values {
+            match value.into_lua(self.lua) {
+                Ok(value) => values.push_front(value),
+                Err(err) => self.values = Err(err),
+            }
+        }
+        self
+    }
+
+    /// Returns the built `MultiValue` or the first error encountered.
+    pub fn build(self) -> Result<MultiValue> {
+        self.values
+    }
+}
+
+impl IntoLuaMulti for MultiValueBuilder<'_> {
+    #[inline]
+    fn into_lua_multi(self, _: &Lua) -> Result<MultiValue> {
+        self.build()
+    }
+}
+
 /// Wraps a variable number of `T`s.
 ///
 /// Can be used to work with variadic functions more easily. Using this type as the last argument of
@@ -247,6 +454,18 @@ impl<T> Variadic<T> {
     pub fn with_capacity(capacity: usize) -> Variadic<T> {
         Variadic(Vec::with_capacity(capacity))
     }
+
+    /// Creates a `Variadic` wrapper from an existing vector of values.
+    #[inline]
+    pub const fn from_vec(vec: Vec<T>) -> Variadic<T> {
+        Variadic(vec)
+    }
+
+    /// Consumes the `Variadic` and returns the underlying vector of values.
+    #[inline]
+    pub fn into_inner(self) -> Vec<T> {
+        self.0
+    }
 }
 
 impl<T> Deref for Variadic<T> {
@@ -297,6 +516,16 @@ impl<T: IntoLua> IntoLuaMulti for Variadic<T> {
     fn into_lua_multi(self, lua: &Lua) -> Result<MultiValue> {
         MultiValue::from_lua_iter(lua, self)
     }
+
+    #[inline]
+    unsafe fn push_into_stack_multi(self, lua: &RawLua) -> Result<c_int> {
+        let n = c_int::try_from(self.0.len()).map_err(|_| Error::StackError)?;
+        check_stack(lua.state(), n + 1)?;
+        for value in self.0 {
+            value.push_into_stack(lua)?;
+        }
+        Ok(n)
+    }
 }
 
 impl<T: FromLua> FromLuaMulti for Variadic<T> {
@@ -310,143 +539,1274 @@ impl<T: FromLua> FromLuaMulti for Variadic<T> {
     }
 }
 
-macro_rules! impl_tuple {
-    () => (
-        impl IntoLuaMulti for () {
-            #[inline]
-            fn into_lua_multi(self, _: &Lua) -> Result<MultiValue> {
-                const { Ok(MultiValue::new()) }
-            }
+/// Like [`Variadic`], but requires at least one value.
+///
+/// When used as the last argument of a Rust callback, conversion fails with a "bad argument"
+/// error pointing at the first missing position if no values were passed, so the callback does not
+/// need to check for emptiness itself.
+///
+/// # Examples
+///
+/// ```
+/// # use mlua::{Lua, NonEmptyVariadic, Result};
+/// # fn main() -> Result<()> {
+/// # let lua = Lua::new();
+/// let max = lua.create_function(|_, vals: NonEmptyVariadic<i64>| {
+///     Ok(vals.iter().copied().max())
+/// })?;
+/// assert_eq!(max.call::<i64>((3, 7, 5))?, 7);
+/// assert!(max.call::<i64>(()).is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NonEmptyVariadic<T>(Vec<T>);
 
-            #[inline]
-            unsafe fn push_into_stack_multi(self, _lua: &RawLua) -> Result<c_int> {
-                Ok(0)
-            }
-        }
+impl<T> NonEmptyVariadic<T> {
+    /// Creates a new `NonEmptyVariadic` from the first value and the remaining ones.
+    pub fn new(first: T, rest: Vec<T>) -> Self {
+        let mut values = Vec::with_capacity(rest.len() + 1);
+        values.push(first);
+        values.extend(rest);
+        NonEmptyVariadic(values)
+    }
 
-        impl FromLuaMulti for () {
-            #[inline]
-            fn from_lua_multi(_values: MultiValue, _lua: &Lua) -> Result<Self> {
-                Ok(())
-            }
+    /// Returns the first value.
+    #[inline]
+    pub fn first(&self) -> &T {
+        &self.0[0]
+    }
 
-            #[inline]
-            unsafe fn from_stack_multi(_nvals: c_int, _lua: &RawLua) -> Result<Self> {
-                Ok(())
-            }
-        }
-    );
+    /// Consumes the wrapper, returning the inner vector.
+    #[inline]
+    pub fn into_vec(self) -> Vec<T> {
+        self.0
+    }
+}
 
-    ($last:ident $($name:ident)*) => (
-        impl<$($name,)* $last> IntoLuaMulti for ($($name,)* $last,)
-            where $($name: IntoLua,)*
-                  $last: IntoLuaMulti
-        {
-            #[allow(unused_mut, non_snake_case)]
-            #[inline]
-            fn into_lua_multi(self, lua: &Lua) -> Result<MultiValue> {
-                let ($($name,)* $last,) = self;
+impl<T> Deref for NonEmptyVariadic<T> {
+    type Target = [T];
 
-                let mut results = $last.into_lua_multi(lua)?;
-                push_reverse!(results, $($name.into_lua(lua)?,)*);
-                Ok(results)
-            }
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
 
-            #[allow(non_snake_case)]
-            #[inline]
-            unsafe fn push_into_stack_multi(self, lua: &RawLua) -> Result<c_int> {
-                let ($($name,)* $last,) = self;
-                let mut nresults = 0;
-                $(
-                    _ = $name;
-                    nresults += 1;
-                )*
-                check_stack(lua.state(), nresults + 1)?;
-                $(
-                    $name.push_into_stack(lua)?;
-                )*
-                nresults += $last.push_into_stack_multi(lua)?;
-                Ok(nresults)
-            }
+/// Fails if the vector is empty, returning it back.
+impl<T> TryFrom<Vec<T>> for NonEmptyVariadic<T> {
+    type Error = Vec<T>;
+
+    #[inline]
+    fn try_from(values: Vec<T>) -> StdResult<Self, Self::Error> {
+        if values.is_empty() {
+            return Err(values);
         }
+        Ok(NonEmptyVariadic(values))
+    }
+}
 
-        impl<$($name,)* $last> FromLuaMulti for ($($name,)* $last,)
-            where $($name: FromLua,)*
-                  $last: FromLuaMulti
-        {
-            #[allow(unused_mut, non_snake_case)]
-            #[inline]
-            fn from_lua_multi(mut values: MultiValue, lua: &Lua) -> Result<Self> {
-                $(let $name = FromLua::from_lua(values.pop_front().unwrap_or(Nil), lua)?;)*
-                let $last = FromLuaMulti::from_lua_multi(values, lua)?;
-                Ok(($($name,)* $last,))
-            }
+impl<T> From<NonEmptyVariadic<T>> for Vec<T> {
+    #[inline]
+    fn from(value: NonEmptyVariadic<T>) -> Self {
+        value.0
+    }
+}
 
-            #[allow(unused_mut, non_snake_case)]
-            #[inline]
-            fn from_lua_args(mut args: MultiValue, mut i: usize, to: Option<&str>, lua: &Lua) -> Result<Self> {
-                $(
-                    let $name = FromLua::from_lua_arg(args.pop_front().unwrap_or(Nil), i, to, lua)?;
-                    i += 1;
-                )*
-                let $last = FromLuaMulti::from_lua_args(args, i, to, lua)?;
-                Ok(($($name,)* $last,))
-            }
+impl<T> IntoIterator for NonEmptyVariadic<T> {
+    type Item = T;
+    type IntoIter = <Vec<T> as IntoIterator>::IntoIter;
 
-            #[allow(unused_mut, non_snake_case)]
-            #[inline]
-            unsafe fn from_stack_multi(mut nvals: c_int, lua: &RawLua) -> Result<Self> {
-                $(
-                    let $name = if nvals > 0 {
-                        nvals -= 1;
-                        FromLua::from_stack(-(nvals + 1), lua)
-                    } else {
-                        FromLua::from_lua(Nil, lua.lua())
-                    }?;
-                )*
-                let $last = FromLuaMulti::from_stack_multi(nvals, lua)?;
-                Ok(($($name,)* $last,))
-            }
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
 
-            #[allow(unused_mut, non_snake_case)]
-            #[inline]
-            unsafe fn from_stack_args(mut nargs: c_int, mut i: usize, to: Option<&str>, lua: &RawLua) -> Result<Self> {
-                $(
-                    let $name = if nargs > 0 {
-                        nargs -= 1;
-                        FromLua::from_stack_arg(-(nargs + 1), i, to, lua)
-                    } else {
-                        FromLua::from_lua_arg(Nil, i, to, lua.lua())
-                    }?;
-                    i += 1;
-                )*
-                let $last = FromLuaMulti::from_stack_args(nargs, i, to, lua)?;
-                Ok(($($name,)* $last,))
-            }
-        }
-    );
+impl<T: IntoLua> IntoLuaMulti for NonEmptyVariadic<T> {
+    #[inline]
+    fn into_lua_multi(self, lua: &Lua) -> Result<MultiValue> {
+        MultiValue::from_lua_iter(lua, self)
+    }
 }
 
-macro_rules! push_reverse {
-    ($multi_value:expr, $first:expr, $($rest:expr,)*) => (
-        push_reverse!($multi_value, $($rest,)*);
-        $multi_value.push_front($first);
-    );
+impl<T: FromLua> FromLuaMulti for NonEmptyVariadic<T> {
+    fn from_lua_multi(mut values: MultiValue, lua: &Lua) -> Result<Self> {
+        if values.is_empty() {
+            return Err(non_empty_error());
+        }
+        let values = values.drain(..).map(|val| T::from_lua(val, lua));
+        values.collect::<Result<Vec<T>>>().map(NonEmptyVariadic)
+    }
 
-    ($multi_value:expr, $first:expr) => (
-        $multi_value.push_front($first);
-    );
+    fn from_lua_args(mut args: MultiValue, i: usize, to: Option<&str>, lua: &Lua) -> Result<Self> {
+        if args.is_empty() {
+            return Err(Error::BadArgument {
+                to: to.map(|s| s.to_string()),
+                pos: i,
+                name: None,
+                cause: Arc::new(non_empty_error()),
+            });
+        }
+        let values = (args.drain(..).enumerate()).map(|(j, val)| T::from_lua_arg(val, i + j, to, lua));
+        values.collect::<Result<Vec<T>>>().map(NonEmptyVariadic)
+    }
 
-    ($multi_value:expr,) => ();
+    #[inline]
+    unsafe fn from_stack_args(nargs: c_int, i: usize, to: Option<&str>, lua: &RawLua) -> Result<Self> {
+        let mut args = MultiValue::with_capacity(nargs as usize);
+        for idx in 0..nargs {
+            args.push_back(lua.stack_value(-nargs + idx, None));
+        }
+        Self::from_lua_args(args, i, to, lua.lua())
+    }
 }
 
-impl_tuple!();
-impl_tuple!(A);
-impl_tuple!(A B);
-impl_tuple!(A B C);
-impl_tuple!(A B C D);
-impl_tuple!(A B C D E);
-impl_tuple!(A B C D E F);
+fn non_empty_error() -> Error {
+    Error::FromLuaConversionError {
+        from: "nil",
+        to: "NonEmptyVariadic".to_string(),
+        message: Some("expected at least 1 argument".to_string()),
+    }
+}
+
+/// A trailing argument that distinguishes a missing value from an explicit `nil`.
+///
+/// Lua does not differentiate between `f()` and `f(nil)` for regular arguments, so `Option<T>`
+/// returns `None` in both cases. `Present<T>` instead looks at the number of values actually
+/// passed:
+///
+/// - `Present(None)` means there was no value in this position.
+/// - `Present(Some(value))` means a value (possibly `nil`) was passed and converted to `T`.
+///
+/// Use `Present<Option<T>>` to accept an explicit `nil` as `Some(None)`.
+///
+/// Like [`Variadic`], this type must be the last argument of a Rust callback, as only the last
+/// element of a tuple sees the remaining argument count. Any values after it are ignored.
+///
+/// # Examples
+///
+/// ```
+/// # use mlua::{Lua, Present, Result};
+/// # fn main() -> Result<()> {
+/// let lua = Lua::new();
+/// let f = lua.create_function(|_, Present(arg): Present<Option<i64>>| {
+///     Ok(match arg {
+///         None => "missing",
+///         Some(None) => "nil",
+///         Some(Some(_)) => "number",
+///     })
+/// })?;
+/// lua.globals().set("f", f)?;
+/// lua.load(r#"
+///     assert(f() == "missing")
+///     assert(f(nil) == "nil")
+///     assert(f(1) == "number")
+/// "#).exec()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Present<T>(pub Option<T>);
+
+impl<T: FromLua> FromLuaMulti for Present<T> {
+    #[inline]
+    fn from_lua_multi(mut values: MultiValue, lua: &Lua) -> Result<Self> {
+        match values.pop_front() {
+            Some(value) => T::from_lua(value, lua).map(|v| Present(Some(v))),
+            None => Ok(Present(None)),
+        }
+    }
+
+    #[inline]
+    fn from_lua_args(mut args: MultiValue, i: usize, to: Option<&str>, lua: &Lua) -> Result<Self> {
+        match args.pop_front() {
+            Some(arg) => T::from_lua_arg(arg, i, to, lua).map(|v| Present(Some(v))),
+            None => Ok(Present(None)),
+        }
+    }
+
+    #[inline]
+    unsafe fn from_stack_multi(nvals: c_int, lua: &RawLua) -> Result<Self> {
+        match nvals {
+            0 => Ok(Present(None)),
+            _ => T::from_stack(-nvals, lua).map(|v| Present(Some(v))),
+        }
+    }
+
+    #[inline]
+    unsafe fn from_stack_args(nargs: c_int, i: usize, to: Option<&str>, lua: &RawLua) -> Result<Self> {
+        match nargs {
+            0 => Ok(Present(None)),
+            _ => T::from_stack_arg(-nargs, i, to, lua).map(|v| Present(Some(v))),
+        }
+    }
+}
+
+/// Explicitly discards any remaining arguments.
+///
+/// Tuples already ignore values beyond their last element, so this type does not change behavior.
+/// Using it as the last argument of a Rust callback documents that extra arguments are expected and
+/// intentionally dropped. The values are never converted.
+///
+/// # Examples
+///
+/// ```
+/// # use mlua::{IgnoreRest, Lua, Result};
+/// # fn main() -> Result<()> {
+/// let lua = Lua::new();
+/// let first = lua.create_function(|_, (n, IgnoreRest): (i64, IgnoreRest)| Ok(n))?;
+/// assert_eq!(first.call::<i64>((1, "two", 3.0))?, 1);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IgnoreRest;
+
+impl FromLuaMulti for IgnoreRest {
+    #[inline]
+    fn from_lua_multi(_: MultiValue, _: &Lua) -> Result<Self> {
+        Ok(IgnoreRest)
+    }
+
+    #[inline]
+    unsafe fn from_stack_multi(_: c_int, _: &RawLua) -> Result<Self> {
+        Ok(IgnoreRest)
+    }
+}
+
+/// Captures the remaining arguments of a callback and converts them lazily.
+///
+/// Like [`Variadic`], this type should be used as the last argument of a Rust callback. Instead of
+/// converting every argument into a new `Vec<T>` upfront, it keeps the original Lua values and
+/// converts each one to `T` when iterated, yielding a [`Result`] per value. This avoids an extra
+/// allocation in hot callbacks that only need to walk the arguments once.
+///
+/// When used as callback arguments, conversion errors are reported as "bad argument" errors with
+/// the position of the offending value, same as for [`Variadic`].
+///
+/// `RestArgs` owns the captured values, so it has no lifetime parameter and may outlive the
+/// callback invocation. It holds a weak reference to the Lua instance, and iterating after the
+/// instance has been destroyed yields an error for each remaining value.
+///
+/// # Examples
+///
+/// ```
+/// # use mlua::{Lua, RestArgs, Result};
+/// # fn main() -> Result<()> {
+/// # let lua = Lua::new();
+/// let sum = lua.create_function(|_, args: RestArgs<i64>| {
+///     let mut sum = 0;
+///     for n in args {
+///         sum += n?;
+///     }
+///     Ok(sum)
+/// })?;
+/// assert_eq!(sum.call::<i64>((1, 2, 3))?, 6);
+/// # Ok(())
+/// # }
+/// ```
+pub struct RestArgs<T = Value> {
+    values: MultiValue,
+    // Position of the next argument and the function name, if created from callback arguments
+    arg: Option<(usize, Option<StdString>)>,
+    lua: WeakLua,
+    _phantom: PhantomData<T>,
+}
+
+impl<T> RestArgs<T> {
+    /// Returns the number of remaining values.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns `true` if there are no remaining values.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Returns the remaining values without converting them.
+    #[inline]
+    pub fn into_values(self) -> MultiValue {
+        self.values
+    }
+}
+
+impl<T> fmt::Debug for RestArgs<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("RestArgs").field(&self.values).finish()
+    }
+}
+
+impl<T: FromLua> Iterator for RestArgs<T> {
+    type Item = Result<T>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = self.values.pop_front()?;
+        let Some(lua) = self.lua.try_upgrade() else {
+            return Some(Err(Error::runtime("Lua instance is destroyed")));
+        };
+        Some(match &mut self.arg {
+            Some((pos, to)) => {
+                *pos += 1;
+                T::from_lua_arg(value, *pos - 1, to.as_deref(), &lua)
+            }
+            None => T::from_lua(value, &lua),
+        })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.values.len(), Some(self.values.len()))
+    }
+}
+
+impl<T: FromLua> ExactSizeIterator for RestArgs<T> {}
+
+impl<T: FromLua> FromLuaMulti for RestArgs<T> {
+    #[inline]
+    fn from_lua_multi(values: MultiValue, lua: &Lua) -> Result<Self> {
+        Ok(RestArgs {
+            values,
+            arg: None,
+            lua: lua.weak(),
+            _phantom: PhantomData,
+        })
+    }
+
+    #[inline]
+    fn from_lua_args(args: MultiValue, i: usize, to: Option<&str>, lua: &Lua) -> Result<Self> {
+        Ok(RestArgs {
+            values: args,
+            arg: Some((i, to.map(|s| s.to_string()))),
+            lua: lua.weak(),
+            _phantom: PhantomData,
+        })
+    }
+
+    #[inline]
+    unsafe fn from_stack_args(nargs: c_int, i: usize, to: Option<&str>, lua: &RawLua) -> Result<Self> {
+        let mut args = MultiValue::with_capacity(nargs as usize);
+        for idx in 0..nargs {
+            args.push_back(lua.stack_value(-nargs + idx, None));
+        }
+        Self::from_lua_args(args, i, to, lua.lua())
+    }
+}
+
+/// Spreads the elements of an array into multiple values.
+///
+/// Returning `Unpacked([a, b, c])` from a Rust callback produces three separate return values,
+/// same as `table.unpack` would do in Lua, instead of a single table.
+///
+/// To return a list whose length is only known at runtime, use [`Variadic`].
+///
+/// # Examples
+///
+/// ```
+/// # use mlua::{Lua, Result, Unpacked};
+/// # fn main() -> Result<()> {
+/// # let lua = Lua::new();
+/// let origin = lua.create_function(|_, ()| Ok(Unpacked([0.0, 1.0, 2.0])))?;
+/// lua.globals().set("origin", origin)?;
+/// lua.load("local x, y, z = origin(); assert(x == 0 and y == 1 and z == 2)").exec()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Unpacked<T>(pub T);
+
+impl<T: IntoLua, const N: usize> IntoLuaMulti for Unpacked<[T; N]> {
+    #[inline]
+    fn into_lua_multi(self, lua: &Lua) -> Result<MultiValue> {
+        MultiValue::from_lua_iter(lua, self.0)
+    }
+
+    #[inline]
+    unsafe fn push_into_stack_multi(self, lua: &RawLua) -> Result<c_int> {
+        let n = c_int::try_from(N).map_err(|_| Error::StackError)?;
+        check_stack(lua.state(), n + 1)?;
+        for value in self.0 {
+            value.push_into_stack(lua)?;
+        }
+        Ok(n)
+    }
+}
+
+/// Spreads a multi-value into its surrounding tuple.
+///
+/// Only the last element of a tuple may produce multiple values; any other element is converted
+/// using [`IntoLua`] and takes exactly one slot. Wrapping an element in `Flatten` lifts this
+/// restriction and splices all of its values inline, so `(a, Flatten(rest), b)` produces `a`,
+/// followed by every value of `rest`, followed by `b`.
+///
+/// Note that this shifts the position of every following value by the number of flattened values.
+/// When the tuple is used as function arguments, positions reported in "bad argument" errors refer
+/// to the flattened list rather than to the tuple elements.
+///
+/// # Examples
+///
+/// ```
+/// # use mlua::{Flatten, Lua, MultiValue, Result};
+/// # fn main() -> Result<()> {
+/// # let lua = Lua::new();
+/// let f = lua.create_function(|lua, ()| {
+///     let rest = MultiValue::from_vec(vec![lua.pack(2)?, lua.pack(3)?]);
+///     Ok((1, Flatten(rest), 4))
+/// })?;
+/// assert_eq!(f.call::<(i32, i32, i32, i32)>(())?, (1, 2, 3, 4));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Flatten<T>(pub T);
+
+impl<T: IntoLuaMulti> IntoLuaMulti for Flatten<T> {
+    #[inline]
+    fn into_lua_multi(self, lua: &Lua) -> Result<MultiValue> {
+        self.0.into_lua_multi(lua)
+    }
+
+    #[inline]
+    unsafe fn push_into_stack_multi(self, lua: &RawLua) -> Result<c_int> {
+        self.0.push_into_stack_multi(lua)
+    }
+}
+
+/// A value that can be used as a non-last element of a tuple converted to [`MultiValue`].
+///
+/// Tuples implement [`IntoLuaMulti`] when every element except the last one implements this
+/// trait, and the last one implements [`IntoLuaMulti`]. It is implemented for every [`IntoLua`]
+/// type, which takes exactly one slot, and for [`Flatten`], which splices any number of values.
+///
+/// Generic code that builds tuples from type parameters needs this trait in its bounds to support
+/// both cases:
+///
+/// ```
+/// # use mlua::{Flatten, IntoLuaMulti, IntoLuaTupleItem, Lua, Result, Variadic};
+/// # fn main() -> Result<()> {
+/// # let lua = Lua::new();
+/// fn tagged<T: IntoLuaTupleItem>(item: T) -> impl IntoLuaMulti {
+///     ("tag", item, "end")
+/// }
+///
+/// assert_eq!(tagged(1).into_lua_multi(&lua)?.len(), 3);
+/// assert_eq!(tagged(Flatten(Variadic::from(vec![1, 2]))).into_lua_multi(&lua)?.len(), 4);
+/// # Ok(())
+/// # }
+/// ```
+pub trait IntoLuaTupleItem {
+    /// Converts the item and prepends the resulting values to `values`.
+    #[doc(hidden)]
+    fn push_front_into(self, values: &mut MultiValue, lua: &Lua) -> Result<()>;
+
+    /// Pushes the item onto the Lua stack, returning the number of pushed values.
+    ///
+    /// The stack must have room for at least `extra + 1` values. `extra` is the number of slots
+    /// still required by the following items.
+    #[doc(hidden)]
+    unsafe fn push_into_stack_item(self, lua: &RawLua, extra: c_int) -> Result<c_int>;
+}
+
+impl<T: IntoLua> IntoLuaTupleItem for T {
+    #[inline]
+    fn push_front_into(self, values: &mut MultiValue, lua: &Lua) -> Result<()> {
+        values.push_front(self.into_lua(lua)?);
+        Ok(())
+    }
+
+    #[inline]
+    unsafe fn push_into_stack_item(self, lua: &RawLua, _extra: c_int) -> Result<c_int> {
+        self.push_into_stack(lua)?;
+        Ok(1)
+    }
+}
+
+impl<T: IntoLuaMulti> IntoLuaTupleItem for Flatten<T> {
+    fn push_front_into(self, values: &mut MultiValue, lua: &Lua) -> Result<()> {
+        let items = self.0.into_lua_multi(lua)?;
+        for value in items.into_iter().rev() {
+            values.push_front(value);
+        }
+        Ok(())
+    }
+
+    unsafe fn push_into_stack_item(self, lua: &RawLua, extra: c_int) -> Result<c_int> {
+        let values = self.0.into_lua_multi(lua.lua())?;
+        let n = c_int::try_from(values.len()).map_err(|_| Error::StackError)?;
+        check_stack(lua.state(), n + extra)?;
+        for value in &values {
+            lua.push_value(value)?;
+        }
+        Ok(n)
+    }
+}
+
+/// Spreads a map into a flat list of alternating keys and values.
+///
+/// Returning `SpreadKv` from a Rust callback produces `k1, v1, k2, v2, ...` as separate return
+/// values, matching Lua APIs that expect flattened key/value lists. The order of pairs follows the
+/// map iteration order.
+///
+/// See [`KvPairs`] for the reverse conversion.
+#[derive(Default, Debug, Clone)]
+pub struct SpreadKv<K, V, S = RandomState>(pub HashMap<K, V, S>);
+
+impl<K: IntoLua, V: IntoLua, S> IntoLuaMulti for SpreadKv<K, V, S> {
+    #[inline]
+    fn into_lua_multi(self, lua: &Lua) -> Result<MultiValue> {
+        let mut values = MultiValue::with_capacity(self.0.len() * 2);
+        for (k, v) in self.0 {
+            values.push_back(k.into_lua(lua)?);
+            values.push_back(v.into_lua(lua)?);
+        }
+        Ok(values)
+    }
+}
+
+/// Collects a flat list of alternating keys and values into a map.
+///
+/// Using `KvPairs` as the last argument of a Rust callback reads `k1, v1, k2, v2, ...` into a
+/// [`HashMap`]. If the number of values is odd, the last key is paired with `nil`.
+///
+/// See [`SpreadKv`] for the reverse conversion.
+#[derive(Default, Debug, Clone)]
+pub struct KvPairs<K, V, S = RandomState>(pub HashMap<K, V, S>);
+
+impl<K, V, S> FromLuaMulti for KvPairs<K, V, S>
+where
+    K: Eq + Hash + FromLua,
+    V: FromLua,
+    S: BuildHasher + Default,
+{
+    #[inline]
+    fn from_lua_multi(values: MultiValue, lua: &Lua) -> Result<Self> {
+        let mut map = HashMap::with_capacity_and_hasher(values.len().div_ceil(2), S::default());
+        let mut values = values.into_iter();
+        while let Some(key) = values.next() {
+            let key = K::from_lua(key, lua)?;
+            let value = V::from_lua(values.next().unwrap_or(Nil), lua)?;
+            map.insert(key, value);
+        }
+        Ok(KvPairs(map))
+    }
+}
+
+/// Wraps a tuple of values to omit the ones that are `nil` instead of passing them positionally.
+///
+/// Returning `(a, None, c)` from a Rust callback produces three values with `nil` in the middle.
+/// Returning `SpreadOptional((a, None, c))` produces only two values, `a` and `c`, with the
+/// following values shifted forward. Any element converted to `nil` (not only `None`) is omitted.
+///
+/// # Examples
+///
+/// ```
+/// # use mlua::{Lua, Result, SpreadOptional};
+/// # fn main() -> Result<()> {
+/// let lua = Lua::new();
+/// let f = lua.create_function(|_, flag: bool| Ok(SpreadOptional((1, flag.then_some(2), 3))))?;
+/// assert_eq!(f.call::<(i32, i32)>(false)?, (1, 3));
+/// assert_eq!(f.call::<(i32, i32, i32)>(true)?, (1, 2, 3));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SpreadOptional<T>(pub T);
+
+macro_rules! impl_spread_optional {
+    ($(#[$meta:meta])* $($name:ident)+) => (
+        $(#[$meta])*
+        impl<$($name,)+> IntoLuaMulti for SpreadOptional<($($name,)+)>
+            where $($name: IntoLua,)+
+        {
+            #[allow(non_snake_case)]
+            fn into_lua_multi(self, lua: &Lua) -> Result<MultiValue> {
+                let ($($name,)+) = self.0;
+                let mut results = MultiValue::new();
+                $(
+                    match $name.into_lua(lua)? {
+                        Nil => {}
+                        value => results.push_back(value),
+                    }
+                )+
+                Ok(results)
+            }
+        }
+    );
+}
+
+impl_spread_optional!(A);
+impl_spread_optional!(A B);
+impl_spread_optional!(A B C);
+impl_spread_optional!(A B C D);
+impl_spread_optional!(A B C D E);
+impl_spread_optional!(A B C D E F);
+impl_spread_optional!(A B C D E F G);
+impl_spread_optional!(A B C D E F G H);
+impl_spread_optional!(A B C D E F G H I);
+impl_spread_optional!(A B C D E F G H I J);
+impl_spread_optional!(A B C D E F G H I J K);
+impl_spread_optional!(A B C D E F G H I J K L);
+impl_spread_optional!(A B C D E F G H I J K L M);
+impl_spread_optional!(A B C D E F G H I J K L M N);
+impl_spread_optional!(A B C D E F G H I J K L M N O);
+impl_spread_optional!(A B C D E F G H I J K L M N O P);
+#[cfg(feature = "tuple-24")]
+impl_spread_optional!(
+    #[cfg_attr(docsrs, doc(cfg(feature = "tuple-24")))]
+    A B C D E F G H I J K L M N O P Q
+);
+#[cfg(feature = "tuple-24")]
+impl_spread_optional!(
+    #[cfg_attr(docsrs, doc(cfg(feature = "tuple-24")))]
+    A B C D E F G H I J K L M N O P Q R
+);
+#[cfg(feature = "tuple-24")]
+impl_spread_optional!(
+    #[cfg_attr(docsrs, doc(cfg(feature = "tuple-24")))]
+    A B C D E F G H I J K L M N O P Q R S
+);
+#[cfg(feature = "tuple-24")]
+impl_spread_optional!(
+    #[cfg_attr(docsrs, doc(cfg(feature = "tuple-24")))]
+    A B C D E F G H I J K L M N O P Q R S T
+);
+#[cfg(feature = "tuple-24")]
+impl_spread_optional!(
+    #[cfg_attr(docsrs, doc(cfg(feature = "tuple-24")))]
+    A B C D E F G H I J K L M N O P Q R S T U
+);
+#[cfg(feature = "tuple-24")]
+impl_spread_optional!(
+    #[cfg_attr(docsrs, doc(cfg(feature = "tuple-24")))]
+    A B C D E F G H I J K L M N O P Q R S T U V
+);
+#[cfg(feature = "tuple-24")]
+impl_spread_optional!(
+    #[cfg_attr(docsrs, doc(cfg(feature = "tuple-24")))]
+    A B C D E F G H I J K L M N O P Q R S T U V W
+);
+#[cfg(feature = "tuple-24")]
+impl_spread_optional!(
+    #[cfg_attr(docsrs, doc(cfg(feature = "tuple-24")))]
+    A B C D E F G H I J K L M N O P Q R S T U V W X
+);
+
+/// Reads a count-prefixed list of values.
+///
+/// Using `Counted<T>` as the argument of a Rust callback reads the first value as a `usize` count
+/// followed by exactly that many values converted to `T`. Extra values after them are ignored. If
+/// fewer values are present, the conversion fails.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Counted<T>(pub Vec<T>);
+
+impl<T: FromLua> FromLuaMulti for Counted<T> {
+    fn from_lua_multi(mut values: MultiValue, lua: &Lua) -> Result<Self> {
+        let count = usize::from_lua(values.pop_front().unwrap_or(Nil), lua)?;
+        if values.len() < count {
+            return Err(Error::FromLuaConversionError {
+                from: "arguments",
+                to: "Counted".to_string(),
+                message: Some(format!("expected {count} values, got {}", values.len())),
+            });
+        }
+        values
+            .drain(..count)
+            .map(|val| T::from_lua(val, lua))
+            .collect::<Result<Vec<T>>>()
+            .map(Counted)
+    }
+}
+
+/// Named fields of a table read positionally into an array of values.
+///
+/// This bridges keyword-style Lua calls like `f{ x = 1, y = 2 }` into positional Rust handling.
+/// The field names are provided at runtime, so `NamedTuple` is constructed explicitly from the
+/// received table (or arguments) rather than through [`FromLuaMulti`].
+///
+/// # Examples
+///
+/// ```
+/// # use mlua::{Lua, MultiValue, NamedTuple, Result};
+/// # fn main() -> Result<()> {
+/// let lua = Lua::new();
+/// let f = lua.create_function(|lua, args: MultiValue| {
+///     let NamedTuple([x, y]) = NamedTuple::from_args(args, ["x", "y"])?;
+///     let (x, y) = (lua.unpack::<i64>(x)?, lua.unpack::<i64>(y)?);
+///     Ok(x + y)
+/// })?;
+/// lua.globals().set("f", f)?;
+/// assert_eq!(lua.load("f{ x = 1, y = 2 }").eval::<i64>()?, 3);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct NamedTuple<const N: usize>(pub [Value; N]);
+
+impl<const N: usize> NamedTuple<N> {
+    /// Reads the fields `names` from `table` in the given order.
+    ///
+    /// Returns an error naming the first field that is missing (or `nil`).
+    pub fn from_table(table: &Table, names: [&str; N]) -> Result<Self> {
+        let mut values = [const { Nil }; N];
+        for (value, name) in values.iter_mut().zip(names) {
+            *value = table.get::<Value>(name)?;
+            if value.is_nil() {
+                return Err(Error::FromLuaConversionError {
+                    from: "table",
+                    to: "NamedTuple".to_string(),
+                    message: Some(format!("missing field `{name}`")),
+                });
+            }
+        }
+        Ok(NamedTuple(values))
+    }
+
+    /// Reads the fields `names` from a table passed as the first argument.
+    pub fn from_args(mut args: MultiValue, names: [&str; N]) -> Result<Self> {
+        match args.pop_front() {
+            Some(Value::Table(table)) => Self::from_table(&table, names),
+            value => Err(Error::FromLuaConversionError {
+                from: value.as_ref().map(Value::type_name).unwrap_or("nil"),
+                to: "NamedTuple".to_string(),
+                message: Some("expected table".to_string()),
+            }),
+        }
+    }
+}
+
+/// A type that can be read positionally from function arguments, one named field at a time.
+///
+/// This is a derive-free alternative to tuple arguments for fixed-shape argument lists. Each field
+/// is read with [`ArgsReader::next`], so a conversion error reports both the argument position
+/// and the field name. Use [`Args<T>`] as the callback argument type to read `T`.
+///
+/// # Examples
+///
+/// ```
+/// # use mlua::{Args, ArgsReader, FromArgs, Lua, Result};
+/// # fn main() -> Result<()> {
+/// struct Repeat {
+///     text: String,
+///     count: usize,
+/// }
+///
+/// impl FromArgs for Repeat {
+///     fn from_args(args: &mut ArgsReader) -> Result<Self> {
+///         Ok(Repeat {
+///             text: args.next("text")?,
+///             count: args.next("count")?,
+///         })
+///     }
+/// }
+///
+/// let lua = Lua::new();
+/// let f = lua.create_function(|_, Args(r): Args<Repeat>| Ok(r.text.repeat(r.count)))?;
+/// assert_eq!(f.call::<String>(("ab", 2))?, "abab");
+/// let err = f.call::<String>(("ab", "x")).unwrap_err();
+/// assert!(err.to_string().contains("bad argument `count`"));
+/// # Ok(())
+/// # }
+/// ```
+pub trait FromArgs: Sized {
+    /// Reads `Self` from the arguments.
+    fn from_args(args: &mut ArgsReader) -> Result<Self>;
+}
+
+/// Reads function arguments one by one, keeping track of the argument position.
+///
+/// See [`FromArgs`] for details.
+pub struct ArgsReader<'a> {
+    args: MultiValue,
+    pos: usize,
+    to: Option<&'a str>,
+    lua: &'a Lua,
+}
+
+impl ArgsReader<'_> {
+    /// Converts the next argument to `T`.
+    ///
+    /// A missing argument is treated as `nil`. On failure returns [`Error::BadArgument`] with the
+    /// current position and the given `name`.
+    pub fn next<T: FromLua>(&mut self, name: &str) -> Result<T> {
+        let pos = self.pos;
+        self.pos += 1;
+        let value = self.args.pop_front().unwrap_or(Nil);
+        T::from_lua(value, self.lua).map_err(|err| Error::BadArgument {
+            to: self.to.map(|s| s.to_string()),
+            pos,
+            name: Some(name.to_string()),
+            cause: Arc::new(err),
+        })
+    }
+
+    /// Returns the position of the next argument (starting from 1).
+    #[inline]
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// Returns the number of arguments that have not been read yet.
+    #[inline]
+    pub fn remaining(&self) -> usize {
+        self.args.len()
+    }
+
+    /// Returns the Lua instance the arguments belong to.
+    #[inline]
+    pub fn lua(&self) -> &Lua {
+        self.lua
+    }
+}
+
+/// Wraps a [`FromArgs`] type to read it from function arguments.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Args<T>(pub T);
+
+impl<T: FromArgs> FromLuaMulti for Args<T> {
+    #[inline]
+    fn from_lua_multi(values: MultiValue, lua: &Lua) -> Result<Self> {
+        Self::from_lua_args(values, 1, None, lua)
+    }
+
+    fn from_lua_args(args: MultiValue, i: usize, to: Option<&str>, lua: &Lua) -> Result<Self> {
+        let mut reader = ArgsReader {
+            args,
+            pos: i,
+            to,
+            lua,
+        };
+        T::from_args(&mut reader).map(Args)
+    }
+
+    #[inline]
+    unsafe fn from_stack_args(nargs: c_int, i: usize, to: Option<&str>, lua: &RawLua) -> Result<Self> {
+        let mut args = MultiValue::with_capacity(nargs as usize);
+        for idx in 0..nargs {
+            args.push_back(lua.stack_value(-nargs + idx, None));
+        }
+        Self::from_lua_args(args, i, to, lua.lua())
+    }
+}
+
+/// Wraps a tuple of arguments to report every conversion error at once.
+///
+/// Regular tuple conversion stops at the first argument that fails to convert. `Checked` converts
+/// all of them and, if any fail, returns a single [`Error::FromLuaConversionError`] listing each
+/// bad position together with its error.
+///
+/// # Examples
+///
+/// ```
+/// # use mlua::{Checked, Lua, Result};
+/// # fn main() -> Result<()> {
+/// let lua = Lua::new();
+/// let f = lua.create_function(|_, Checked((n, s)): Checked<(usize, String)>| Ok(s.repeat(n)))?;
+/// let err = f.call::<String>(("x", false)).unwrap_err().to_string();
+/// assert!(err.contains("bad argument #1") && err.contains("bad argument #2"));
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`Error::FromLuaConversionError`]: crate::Error::FromLuaConversionError
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Checked<T>(pub T);
+
+fn checked_error(errors: Vec<(usize, Error)>, to: Option<&str>) -> Error {
+    let message = errors
+        .iter()
+        .map(|(i, err)| format!("bad argument #{i}: {err}"))
+        .collect::<Vec<_>>()
+        .join("; ");
+    Error::FromLuaConversionError {
+        from: "arguments",
+        to: to.unwrap_or("Checked").to_string(),
+        message: Some(message),
+    }
+}
+
+macro_rules! impl_checked {
+    ($(#[$meta:meta])* $($name:ident)+) => (
+        $(#[$meta])*
+        impl<$($name,)+> FromLuaMulti for Checked<($($name,)+)>
+            where $($name: FromLua,)+
+        {
+            #[inline]
+            fn from_lua_multi(values: MultiValue, lua: &Lua) -> Result<Self> {
+                Self::from_lua_args(values, 1, None, lua)
+            }
+
+            #[allow(non_snake_case, unused_assignments)]
+            fn from_lua_args(mut args: MultiValue, mut i: usize, to: Option<&str>, lua: &Lua) -> Result<Self> {
+                let mut errors = Vec::new();
+                $(
+                    let $name = match $name::from_lua(args.pop_front().unwrap_or(Nil), lua) {
+                        Ok(value) => Some(value),
+                        Err(err) => {
+                            errors.push((i, err));
+                            None
+                        }
+                    };
+                    i += 1;
+                )+
+                match ($($name,)+) {
+                    ($(Some($name),)+) => Ok(Checked(($($name,)+))),
+                    _ => Err(checked_error(errors, to)),
+                }
+            }
+
+            #[inline]
+            unsafe fn from_stack_args(nargs: c_int, i: usize, to: Option<&str>, lua: &RawLua) -> Result<Self> {
+                let mut args = MultiValue::with_capacity(nargs as usize);
+                for idx in 0..nargs {
+                    args.push_back(lua.stack_value(-nargs + idx, None));
+                }
+                Self::from_lua_args(args, i, to, lua.lua())
+            }
+        }
+    );
+}
+
+impl_checked!(A);
+impl_checked!(A B);
+impl_checked!(A B C);
+impl_checked!(A B C D);
+impl_checked!(A B C D E);
+impl_checked!(A B C D E F);
+impl_checked!(A B C D E F G);
+impl_checked!(A B C D E F G H);
+impl_checked!(A B C D E F G H I);
+impl_checked!(A B C D E F G H I J);
+impl_checked!(A B C D E F G H I J K);
+impl_checked!(A B C D E F G H I J K L);
+impl_checked!(A B C D E F G H I J K L M);
+impl_checked!(A B C D E F G H I J K L M N);
+impl_checked!(A B C D E F G H I J K L M N O);
+impl_checked!(A B C D E F G H I J K L M N O P);
+#[cfg(feature = "tuple-24")]
+impl_checked!(
+    #[cfg_attr(docsrs, doc(cfg(feature = "tuple-24")))]
+    A B C D E F G H I J K L M N O P Q
+);
+#[cfg(feature = "tuple-24")]
+impl_checked!(
+    #[cfg_attr(docsrs, doc(cfg(feature = "tuple-24")))]
+    A B C D E F G H I J K L M N O P Q R
+);
+#[cfg(feature = "tuple-24")]
+impl_checked!(
+    #[cfg_attr(docsrs, doc(cfg(feature = "tuple-24")))]
+    A B C D E F G H I J K L M N O P Q R S
+);
+#[cfg(feature = "tuple-24")]
+impl_checked!(
+    #[cfg_attr(docsrs, doc(cfg(feature = "tuple-24")))]
+    A B C D E F G H I J K L M N O P Q R S T
+);
+#[cfg(feature = "tuple-24")]
+impl_checked!(
+    #[cfg_attr(docsrs, doc(cfg(feature = "tuple-24")))]
+    A B C D E F G H I J K L M N O P Q R S T U
+);
+#[cfg(feature = "tuple-24")]
+impl_checked!(
+    #[cfg_attr(docsrs, doc(cfg(feature = "tuple-24")))]
+    A B C D E F G H I J K L M N O P Q R S T U V
+);
+#[cfg(feature = "tuple-24")]
+impl_checked!(
+    #[cfg_attr(docsrs, doc(cfg(feature = "tuple-24")))]
+    A B C D E F G H I J K L M N O P Q R S T U V W
+);
+#[cfg(feature = "tuple-24")]
+impl_checked!(
+    #[cfg_attr(docsrs, doc(cfg(feature = "tuple-24")))]
+    A B C D E F G H I J K L M N O P Q R S T U V W X
+);
+
+/// Reads a tuple from the sequence part of a single Lua table.
+///
+/// [`FromLua`] converts the values at positions `1..=N` of the table into a tuple of `N` elements,
+/// failing if the table has fewer than `N` elements. Any extra elements are ignored. Tables are
+/// read using raw access (metamethods are not invoked).
+///
+/// [`IntoLua`] does the opposite and creates a sequence table from the tuple.
+///
+/// # Examples
+///
+/// ```
+/// # use mlua::{Lua, Result, TableTuple};
+/// # fn main() -> Result<()> {
+/// let lua = Lua::new();
+/// let TableTuple((n, s, b)) = lua.load("{1, 'x', true}").eval::<TableTuple<(i64, String, bool)>>()?;
+/// assert_eq!((n, s.as_str(), b), (1, "x", true));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TableTuple<T>(pub T);
+
+macro_rules! impl_table_tuple {
+    ($(#[$meta:meta])* $n:literal $($name:ident)+) => (
+        $(#[$meta])*
+        impl<$($name,)+> IntoLua for TableTuple<($($name,)+)>
+            where $($name: IntoLua,)+
+        {
+            #[allow(non_snake_case, unused_assignments)]
+            fn into_lua(self, lua: &Lua) -> Result<Value> {
+                let ($($name,)+) = self.0;
+                let table = lua.create_table_with_capacity($n, 0)?;
+                let mut i = 0;
+                $(
+                    i += 1;
+                    table.raw_set(i, $name)?;
+                )+
+                Ok(Value::Table(table))
+            }
+        }
+
+        $(#[$meta])*
+        impl<$($name,)+> FromLua for TableTuple<($($name,)+)>
+            where $($name: FromLua,)+
+        {
+            #[allow(non_snake_case, unused_assignments)]
+            fn from_lua(value: Value, lua: &Lua) -> Result<Self> {
+                let table = match value {
+                    Value::Table(table) => table,
+                    _ => return Err(Error::FromLuaConversionError {
+                        from: value.type_name(),
+                        to: "TableTuple".to_string(),
+                        message: Some("expected table".to_string()),
+                    }),
+                };
+                let len = table.raw_len();
+                if len < $n {
+                    return Err(Error::FromLuaConversionError {
+                        from: "table",
+                        to: "TableTuple".to_string(),
+                        message: Some(format!("expected table of length {}, got {len}", $n)),
+                    });
+                }
+                let mut i = 0;
+                $(
+                    i += 1;
+                    let $name = $name::from_lua(table.raw_get(i)?, lua)?;
+                )+
+                Ok(TableTuple(($($name,)+)))
+            }
+        }
+    );
+}
+
+impl_table_tuple!(1 A);
+impl_table_tuple!(2 A B);
+impl_table_tuple!(3 A B C);
+impl_table_tuple!(4 A B C D);
+impl_table_tuple!(5 A B C D E);
+impl_table_tuple!(6 A B C D E F);
+impl_table_tuple!(7 A B C D E F G);
+impl_table_tuple!(8 A B C D E F G H);
+impl_table_tuple!(9 A B C D E F G H I);
+impl_table_tuple!(10 A B C D E F G H I J);
+impl_table_tuple!(11 A B C D E F G H I J K);
+impl_table_tuple!(12 A B C D E F G H I J K L);
+impl_table_tuple!(13 A B C D E F G H I J K L M);
+impl_table_tuple!(14 A B C D E F G H I J K L M N);
+impl_table_tuple!(15 A B C D E F G H I J K L M N O);
+impl_table_tuple!(16 A B C D E F G H I J K L M N O P);
+#[cfg(feature = "tuple-24")]
+impl_table_tuple!(
+    #[cfg_attr(docsrs, doc(cfg(feature = "tuple-24")))]
+    17 A B C D E F G H I J K L M N O P Q
+);
+#[cfg(feature = "tuple-24")]
+impl_table_tuple!(
+    #[cfg_attr(docsrs, doc(cfg(feature = "tuple-24")))]
+    18 A B C D E F G H I J K L M N O P Q R
+);
+#[cfg(feature = "tuple-24")]
+impl_table_tuple!(
+    #[cfg_attr(docsrs, doc(cfg(feature = "tuple-24")))]
+    19 A B C D E F G H I J K L M N O P Q R S
+);
+#[cfg(feature = "tuple-24")]
+impl_table_tuple!(
+    #[cfg_attr(docsrs, doc(cfg(feature = "tuple-24")))]
+    20 A B C D E F G H I J K L M N O P Q R S T
+);
+#[cfg(feature = "tuple-24")]
+impl_table_tuple!(
+    #[cfg_attr(docsrs, doc(cfg(feature = "tuple-24")))]
+    21 A B C D E F G H I J K L M N O P Q R S T U
+);
+#[cfg(feature = "tuple-24")]
+impl_table_tuple!(
+    #[cfg_attr(docsrs, doc(cfg(feature = "tuple-24")))]
+    22 A B C D E F G H I J K L M N O P Q R S T U V
+);
+#[cfg(feature = "tuple-24")]
+impl_table_tuple!(
+    #[cfg_attr(docsrs, doc(cfg(feature = "tuple-24")))]
+    23 A B C D E F G H I J K L M N O P Q R S T U V W
+);
+#[cfg(feature = "tuple-24")]
+impl_table_tuple!(
+    #[cfg_attr(docsrs, doc(cfg(feature = "tuple-24")))]
+    24 A B C D E F G H I J K L M N O P Q R S T U V W X
+);
+
+macro_rules! impl_tuple {
+    () => (
+        impl IntoLuaMulti for () {
+            #[inline]
+            fn into_lua_multi(self, _: &Lua) -> Result<MultiValue> {
+                const { Ok(MultiValue::new()) }
+            }
+
+            #[inline]
+            unsafe fn push_into_stack_multi(self, _lua: &RawLua) -> Result<c_int> {
+                Ok(0)
+            }
+        }
+
+        impl FromLuaMulti for () {
+            #[inline]
+            fn from_lua_multi(_values: MultiValue, _lua: &Lua) -> Result<Self> {
+                Ok(())
+            }
+
+            #[inline]
+            unsafe fn from_stack_multi(_nvals: c_int, _lua: &RawLua) -> Result<Self> {
+                Ok(())
+            }
+        }
+    );
+
+    ($(#[$meta:meta])* $last:ident $($name:ident)*) => (
+        $(#[$meta])*
+        impl<$($name,)* $last> IntoLuaMulti for ($($name,)* $last,)
+            where $($name: IntoLuaTupleItem,)*
+                  $last: IntoLuaMulti
+        {
+            #[allow(unused_mut, non_snake_case)]
+            #[inline]
+            fn into_lua_multi(self, lua: &Lua) -> Result<MultiValue> {
+                let ($($name,)* $last,) = self;
+
+                let mut results = $last.into_lua_multi(lua)?;
+                push_reverse!(results, lua, $($name,)*);
+                Ok(results)
+            }
+
+            #[allow(unused_mut, non_snake_case)]
+            #[inline]
+            unsafe fn push_into_stack_multi(self, lua: &RawLua) -> Result<c_int> {
+                let ($($name,)* $last,) = self;
+                let mut nitems = 0;
+                $(
+                    _ = $name;
+                    nitems += 1;
+                )*
+                check_stack(lua.state(), nitems + 1)?;
+                let mut nresults = 0;
+                $(
+                    nitems -= 1;
+                    nresults += $name.push_into_stack_item(lua, nitems + 1)?;
+                )*
+                nresults += $last.push_into_stack_multi(lua)?;
+                Ok(nresults)
+            }
+        }
+
+        $(#[$meta])*
+        impl<$($name,)* $last> FromLuaMulti for ($($name,)* $last,)
+            where $($name: FromLua,)*
+                  $last: FromLuaMulti
+        {
+            #[allow(unused_mut, non_snake_case)]
+            #[inline]
+            fn from_lua_multi(mut values: MultiValue, lua: &Lua) -> Result<Self> {
+                let mut i = 1;
+                $(
+                    let $name = FromLua::from_lua(values.pop_front().unwrap_or(Nil), lua)
+                        .with_context(|_| format!("value #{i}"))?;
+                    i += 1;
+                )*
+                let $last = FromLuaMulti::from_lua_multi(values, lua)
+                    .with_context(|_| format!("value #{i}"))?;
+                Ok(($($name,)* $last,))
+            }
+
+            #[allow(unused_mut, non_snake_case)]
+            #[inline]
+            fn from_lua_args(mut args: MultiValue, mut i: usize, to: Option<&str>, lua: &Lua) -> Result<Self> {
+                $(
+                    let $name = FromLua::from_lua_arg(args.pop_front().unwrap_or(Nil), i, to, lua)?;
+                    i += 1;
+                )*
+                let $last = FromLuaMulti::from_lua_args(args, i, to, lua)?;
+                Ok(($($name,)* $last,))
+            }
+
+            #[allow(unused_mut, non_snake_case)]
+            #[inline]
+            unsafe fn from_stack_multi(mut nvals: c_int, lua: &RawLua) -> Result<Self> {
+                let mut i = 1;
+                $(
+                    let $name = if nvals > 0 {
+                        nvals -= 1;
+                        FromLua::from_stack(-(nvals + 1), lua)
+                    } else {
+                        FromLua::from_lua(Nil, lua.lua())
+                    }
+                    .with_context(|_| format!("value #{i}"))?;
+                    i += 1;
+                )*
+                let $last = FromLuaMulti::from_stack_multi(nvals, lua)
+                    .with_context(|_| format!("value #{i}"))?;
+                Ok(($($name,)* $last,))
+            }
+
+            #[allow(unused_mut, non_snake_case)]
+            #[inline]
+            unsafe fn from_stack_args(mut nargs: c_int, mut i: usize, to: Option<&str>, lua: &RawLua) -> Result<Self> {
+                $(
+                    let $name = if nargs > 0 {
+                        nargs -= 1;
+                        FromLua::from_stack_arg(-(nargs + 1), i, to, lua)
+                    } else {
+                        FromLua::from_lua_arg(Nil, i, to, lua.lua())
+                    }?;
+                    i += 1;
+                )*
+                let $last = FromLuaMulti::from_stack_args(nargs, i, to, lua)?;
+                Ok(($($name,)* $last,))
+            }
+        }
+    );
+}
+
+macro_rules! push_reverse {
+    ($multi_value:expr, $lua:expr, $first:expr, $($rest:expr,)*) => (
+        push_reverse!($multi_value, $lua, $($rest,)*);
+        $first.push_front_into(&mut $multi_value, $lua)?;
+    );
+
+    ($multi_value:expr, $lua:expr, $first:expr) => (
+        $first.push_front_into(&mut $multi_value, $lua)?;
+    );
+
+    ($multi_value:expr, $lua:expr,) => ();
+}
+
+impl_tuple!();
+impl_tuple!(A);
+impl_tuple!(A B);
+impl_tuple!(A B C);
+impl_tuple!(A B C D);
+impl_tuple!(A B C D E);
+impl_tuple!(A B C D E F);
 impl_tuple!(A B C D E F G);
 impl_tuple!(A B C D E F G H);
 impl_tuple!(A B C D E F G H I);
@@ -458,6 +1818,48 @@ impl_tuple!(A B C D E F G H I J K L M N);
 impl_tuple!(A B C D E F G H I J K L M N O);
 impl_tuple!(A B C D E F G H I J K L M N O P);
 
+// Tuples with more than 16 elements increase compile time, so they are opt-in
+#[cfg(feature = "tuple-24")]
+impl_tuple!(
+    #[cfg_attr(docsrs, doc(cfg(feature = "tuple-24")))]
+    A B C D E F G H I J K L M N O P Q
+);
+#[cfg(feature = "tuple-24")]
+impl_tuple!(
+    #[cfg_attr(docsrs, doc(cfg(feature = "tuple-24")))]
+    A B C D E F G H I J K L M N O P Q R
+);
+#[cfg(feature = "tuple-24")]
+impl_tuple!(
+    #[cfg_attr(docsrs, doc(cfg(feature = "tuple-24")))]
+    A B C D E F G H I J K L M N O P Q R S
+);
+#[cfg(feature = "tuple-24")]
+impl_tuple!(
+    #[cfg_attr(docsrs, doc(cfg(feature = "tuple-24")))]
+    A B C D E F G H I J K L M N O P Q R S T
+);
+#[cfg(feature = "tuple-24")]
+impl_tuple!(
+    #[cfg_attr(docsrs, doc(cfg(feature = "tuple-24")))]
+    A B C D E F G H I J K L M N O P Q R S T U
+);
+#[cfg(feature = "tuple-24")]
+impl_tuple!(
+    #[cfg_attr(docsrs, doc(cfg(feature = "tuple-24")))]
+    A B C D E F G H I J K L M N O P Q R S T U V
+);
+#[cfg(feature = "tuple-24")]
+impl_tuple!(
+    #[cfg_attr(docsrs, doc(cfg(feature = "tuple-24")))]
+    A B C D E F G H I J K L M N O P Q R S T U V W
+);
+#[cfg(feature = "tuple-24")]
+impl_tuple!(
+    #[cfg_attr(docsrs, doc(cfg(feature = "tuple-24")))]
+    A B C D E F G H I J K L M N O P Q R S T U V W X
+);
+
 #[cfg(test)]
 mod assertions {
     use super::*;
@@ -9,6 +9,7 @@ use crate::error::Result;
 use crate::private::Sealed;
 use crate::state::Lua;
 use crate::table::Table;
+use crate::traits::{FromLua, IntoLua};
 use crate::util::check_stack;
 use crate::value::Value;
 
@@ -229,6 +230,79 @@ impl LuaSerdeExt for Lua {
     }
 }
 
+/// A wrapper that converts any [`Serialize`] type into a Lua value.
+///
+/// This is a shortcut for [`LuaSerdeExt::to_value`] that can be used anywhere an [`IntoLua`]
+/// value is expected.
+///
+/// Requires `feature = "serialize"`
+///
+/// # Example
+///
+/// ```
+/// use mlua::{Lua, Result, Serialized};
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// fn main() -> Result<()> {
+///     let lua = Lua::new();
+///     lua.globals().set("point", Serialized(Point { x: 1, y: 2 }))?;
+///     lua.load("assert(point.x == 1 and point.y == 2)").exec()
+/// }
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "serialize")))]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Serialized<T>(pub T);
+
+impl<T: Serialize> IntoLua for Serialized<T> {
+    #[inline]
+    fn into_lua(self, lua: &Lua) -> Result<Value> {
+        lua.to_value(&self.0)
+    }
+}
+
+/// A wrapper that converts a Lua value into any [`DeserializeOwned`] type.
+///
+/// This is a shortcut for [`LuaSerdeExt::from_value`] that can be used anywhere a [`FromLua`]
+/// value is expected.
+///
+/// Requires `feature = "serialize"`
+///
+/// # Example
+///
+/// ```
+/// use mlua::{Deserialized, Lua, Result};
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// fn main() -> Result<()> {
+///     let lua = Lua::new();
+///     let Deserialized(point) = lua.load("{x = 1, y = 2}").eval::<Deserialized<Point>>()?;
+///     assert_eq!((point.x, point.y), (1, 2));
+///     Ok(())
+/// }
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "serialize")))]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Deserialized<T>(pub T);
+
+impl<T: DeserializeOwned> FromLua for Deserialized<T> {
+    #[inline]
+    fn from_lua(value: Value, lua: &Lua) -> Result<Self> {
+        lua.from_value(value).map(Deserialized)
+    }
+}
+
 // Uses 2 stack spaces and calls checkstack.
 pub(crate) unsafe fn init_metatables(state: *mut ffi::lua_State) -> Result<()> {
     check_stack(state, 2)?;
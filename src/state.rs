@@ -5,6 +5,8 @@ use std::ops::Deref;
 use std::os::raw::c_int;
 use std::panic::Location;
 use std::result::Result as StdResult;
+use std::sync::Arc;
+use std::time::Duration;
 use std::{fmt, mem, ptr};
 
 use crate::chunk::{AsChunk, Chunk};
@@ -21,7 +23,7 @@ use crate::thread::Thread;
 use crate::traits::{FromLua, FromLuaMulti, IntoLua, IntoLuaMulti};
 use crate::types::{
     AppDataRef, AppDataRefMut, ArcReentrantMutexGuard, Integer, LuaType, MaybeSend, Number, ReentrantMutex,
-    ReentrantMutexGuard, RegistryKey, VmState, XRc, XWeak,
+    ReentrantMutexGuard, RegistryKey, StringInterner, VmState, XRc, XWeak,
 };
 use crate::userdata::{AnyUserData, UserData, UserDataProxy, UserDataRegistry, UserDataStorage};
 use crate::util::{
@@ -1194,6 +1196,58 @@ impl Lua {
         })
     }
 
+    /// Creates a Lua function that subtracts one duration from another without underflowing.
+    ///
+    /// The function takes two durations `a` and `b` (numbers of seconds) and returns `a - b`.
+    /// If `b` is greater than `a`, it returns `nil` and an error message, following the common Lua
+    /// idiom, instead of raising an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mlua::{Lua, Result};
+    /// # fn main() -> Result<()> {
+    /// let lua = Lua::new();
+    /// lua.globals().set("duration_sub", lua.create_duration_sub()?)?;
+    /// lua.load(r#"
+    ///     assert(duration_sub(5, 1.5) == 3.5)
+    ///     local d, err = duration_sub(1, 2)
+    ///     assert(d == nil and err ~= nil)
+    /// "#).exec()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create_duration_sub(&self) -> Result<Function> {
+        self.create_function(|_, (a, b): (Duration, Duration)| {
+            Ok(a.checked_sub(b).ok_or("duration subtraction underflow"))
+        })
+    }
+
+    /// Creates a Lua function that checks whether a duration exceeds the given `limit`.
+    ///
+    /// The function takes a duration (number of seconds) and returns `true` if it is strictly
+    /// greater than `limit`, or `false` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use mlua::{Lua, Result};
+    /// # fn main() -> Result<()> {
+    /// let lua = Lua::new();
+    /// let timed_out = lua.create_timeout_guard(Duration::from_secs(2))?;
+    /// lua.globals().set("timed_out", timed_out)?;
+    /// lua.load(r#"
+    ///     assert(not timed_out(1.5))
+    ///     assert(timed_out(2.5))
+    /// "#).exec()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create_timeout_guard(&self, limit: Duration) -> Result<Function> {
+        self.create_function(move |_, elapsed: Duration| Ok(elapsed > limit))
+    }
+
     /// Wraps a C function, creating a callable Lua function handle to it.
     ///
     /// # Safety
@@ -1923,6 +1977,53 @@ impl Lua {
         extra.app_data.remove()
     }
 
+    /// Returns the per-Lua [`StringInterner`] used to deduplicate strings.
+    ///
+    /// See [`InternedKeys`] for reading tables with interned keys.
+    ///
+    /// [`InternedKeys`]: crate::InternedKeys
+    pub fn string_interner(&self) -> Arc<StringInterner> {
+        let lua = self.lock();
+        let extra = unsafe { &*lua.extra.get() };
+        extra.string_interner.clone()
+    }
+
+    /// Returns the time elapsed since an internal epoch of this Lua instance.
+    ///
+    /// The clock is monotonic: successive calls never return a smaller value. The epoch is set when
+    /// the Lua instance is created.
+    ///
+    /// See [`Lua::create_monotonic_clock`] to expose the clock to Lua.
+    pub fn monotonic_now(&self) -> Duration {
+        let lua = self.lock();
+        let extra = unsafe { &*lua.extra.get() };
+        extra.monotonic_epoch.elapsed()
+    }
+
+    /// Creates a Lua function that returns the value of [`Lua::monotonic_now`].
+    ///
+    /// The function returns the number of seconds (with a fractional part) elapsed since the
+    /// internal epoch of this Lua instance. Successive calls never return a smaller value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mlua::{Lua, Result};
+    /// # fn main() -> Result<()> {
+    /// let lua = Lua::new();
+    /// lua.globals().set("monotonic_now", lua.create_monotonic_clock()?)?;
+    /// lua.load(r#"
+    ///     local t1 = monotonic_now()
+    ///     local t2 = monotonic_now()
+    ///     assert(t2 >= t1)
+    /// "#).exec()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create_monotonic_clock(&self) -> Result<Function> {
+        self.create_function(|lua, ()| Ok(lua.monotonic_now()))
+    }
+
     /// Returns an internal `Poll::Pending` constant used for executing async callbacks.
     #[cfg(feature = "async")]
     #[doc(hidden)]
@@ -2005,6 +2106,14 @@ impl WeakLua {
             collect_garbage: false,
         }
     }
+
+    #[inline(always)]
+    pub(crate) fn try_upgrade(&self) -> Option<Lua> {
+        Some(Lua {
+            raw: self.0.upgrade()?,
+            collect_garbage: false,
+        })
+    }
 }
 
 impl PartialEq for WeakLua {
@@ -5,6 +5,7 @@ use std::os::raw::{c_int, c_void};
 use std::ptr;
 use std::rc::Rc;
 use std::sync::Arc;
+use std::time::Instant;
 
 use parking_lot::Mutex;
 use rustc_hash::FxHashMap;
@@ -12,7 +13,7 @@ use rustc_hash::FxHashMap;
 use crate::error::Result;
 use crate::state::RawLua;
 use crate::stdlib::StdLib;
-use crate::types::{AppData, ReentrantMutex, XRc};
+use crate::types::{AppData, ReentrantMutex, StringInterner, XRc};
 use crate::userdata::RawUserDataRegistry;
 use crate::util::{get_internal_metatable, push_internal_userdata, TypeKey, WrappedFailure};
 
@@ -47,6 +48,11 @@ pub(crate) struct ExtraData {
     // Container to store arbitrary data (extensions)
     pub(super) app_data: AppData,
 
+    // Cache of interned strings
+    pub(super) string_interner: Arc<StringInterner>,
+    // Epoch for `Lua::monotonic_now`
+    pub(super) monotonic_epoch: Instant,
+
     pub(super) safe: bool,
     pub(super) libs: StdLib,
     // Used in module mode
@@ -153,6 +159,8 @@ impl ExtraData {
             last_checked_userdata_mt: (ptr::null(), None),
             registry_unref_list: Arc::new(Mutex::new(Some(Vec::new()))),
             app_data: AppData::default(),
+            string_interner: Arc::default(),
+            monotonic_epoch: Instant::now(),
             safe: false,
             libs: StdLib::NONE,
             skip_memory_check: false,
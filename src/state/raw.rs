@@ -533,7 +533,8 @@ impl RawLua {
         push_table(state, lower_bound, 0, protect)?;
         for (i, v) in iter.enumerate() {
             self.push(v)?;
-            if protect {
+            // Setting an element within the preallocated array part cannot trigger a memory error
+            if protect && i >= lower_bound {
                 protect_lua!(state, 2, 1, |state| {
                     ffi::lua_rawseti(state, -2, (i + 1) as Integer);
                 })?;
@@ -6,7 +6,7 @@ use std::string::String as StdString;
 
 use crate::error::{Error, Result};
 use crate::function::Function;
-use crate::state::{LuaGuard, RawLua};
+use crate::state::{Lua, LuaGuard, RawLua};
 use crate::traits::{FromLua, FromLuaMulti, IntoLua, IntoLuaMulti, ObjectLike};
 use crate::types::{Integer, LuaType, ValueRef};
 use crate::util::{assert_stack, check_stack, get_metatable_ptr, StackGuard};
@@ -1088,6 +1088,89 @@ impl Serialize for SerializableTable<'_> {
     }
 }
 
+/// Shape of a Lua table, as returned by [`Value::table_kind`].
+///
+/// [`Value::table_kind`]: crate::Value::table_kind
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TableKind {
+    /// The table has no entries.
+    Empty,
+    /// All entries belong to the sequence part of the table (`1..=#t`).
+    Array,
+    /// The table has no sequence part.
+    Map,
+    /// The table has both a sequence part and other keys.
+    Mixed,
+}
+
+/// A helper to build a Lua table using method chaining.
+///
+/// Errors are deferred until [`TableBuilder::build`] is called, so there is no need to handle
+/// each intermediate step. A builder can also be used as a value for another builder to create
+/// nested tables.
+///
+/// # Examples
+///
+/// ```
+/// # use mlua::{Lua, Result, TableBuilder};
+/// # fn main() -> Result<()> {
+/// let lua = Lua::new();
+/// let config = TableBuilder::new(&lua)
+///     .set("name", "server")
+///     .set("ports", TableBuilder::new(&lua).seq([80, 443]))
+///     .build()?;
+/// assert_eq!(config.get::<String>("name")?, "server");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct TableBuilder {
+    table: Result<Table>,
+}
+
+impl TableBuilder {
+    /// Creates a new builder for an empty table.
+    pub fn new(lua: &Lua) -> Self {
+        TableBuilder {
+            table: lua.create_table(),
+        }
+    }
+
+    /// Sets a key-value pair in the table.
+    ///
+    /// This method does not invoke any metamethods.
+    pub fn set(mut self, key: impl IntoLua, value: impl IntoLua) -> Self {
+        if let Ok(table) = &self.table {
+            if let Err(err) = table.raw_set(key, value) {
+                self.table = Err(err);
+            }
+        }
+        self
+    }
+
+    /// Appends values from the iterator to the sequence part of the table.
+    pub fn seq<T: IntoLua>(mut self, iter: impl IntoIterator<Item = T>) -> Self {
+        if let Ok(table) = &self.table {
+            if let Err(err) = iter.into_iter().try_for_each(|value| table.raw_push(value)) {
+                self.table = Err(err);
+            }
+        }
+        self
+    }
+
+    /// Returns the built table or the first error encountered.
+    pub fn build(self) -> Result<Table> {
+        self.table
+    }
+}
+
+impl IntoLua for TableBuilder {
+    #[inline]
+    fn into_lua(self, _: &Lua) -> Result<Value> {
+        self.build().map(Value::Table)
+    }
+}
+
 /// An iterator over the pairs of a Lua table.
 ///
 /// This struct is created by the [`Table::pairs`] method.
@@ -16,9 +16,29 @@ pub(crate) type BoxFuture<'a, T> = futures_util::future::BoxFuture<'a, T>;
 pub(crate) type BoxFuture<'a, T> = futures_util::future::LocalBoxFuture<'a, T>;
 
 pub use app_data::{AppData, AppDataRef, AppDataRefMut};
+pub use blob::TaggedBlob;
+pub use color::Color;
 pub use either::Either;
+pub use flags::{FlagTable, Flags};
+pub use geo::LatLon;
+pub use hex::{Digest, Hex16};
+pub use interner::{InternedKeys, StringInterner};
+pub use lazy::Lazy;
+pub use number::{BasisPoints, FiniteFloat, Fraction01, Index, LuaNum, NumberOrString};
+#[cfg(feature = "rayon")]
+pub use par_vec::ParVec;
+pub use quantity::Quantity;
+pub use range::RangeQuery;
+pub use rect::Rect;
 pub use registry_key::RegistryKey;
+pub use sequence::{SeqIter, Sequence, SparseArray};
+pub use snapshot::Snapshot;
+pub use strict::{StrictBool, StrictInt, StrictString};
+#[cfg(feature = "chrono")]
+pub use time::UnixTimestamp;
+pub use time::{Deadline, DurationRich, Rate, SaturatingDuration, SplitDuration};
 pub(crate) use value_ref::ValueRef;
+pub use wrapping::WrappingInt;
 
 /// Type of Lua integer numbers.
 pub type Integer = ffi::lua_Integer;
@@ -126,9 +146,27 @@ impl LuaType for LightUserData {
 }
 
 mod app_data;
+mod blob;
+mod color;
+mod flags;
+mod geo;
+mod hex;
+mod interner;
+mod lazy;
+mod number;
+#[cfg(feature = "rayon")]
+mod par_vec;
+mod quantity;
+mod range;
+mod rect;
 mod registry_key;
+mod sequence;
+mod snapshot;
+mod strict;
 mod sync;
+mod time;
 mod value_ref;
+mod wrapping;
 
 #[cfg(test)]
 mod assertions {
@@ -0,0 +1,51 @@
+use crate::error::{Error, Result};
+use crate::state::Lua;
+use crate::traits::{FromLua, IntoLua};
+use crate::value::Value;
+
+/// A binary payload tagged with a format version.
+///
+/// In Lua the blob is represented as a table `{ version = 1, data = "..." }`, where `data` is a
+/// byte string. [`FromLua`] requires `data` to be a Lua string; numbers are not coerced.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct TaggedBlob {
+    pub version: u16,
+    pub data: Vec<u8>,
+}
+
+impl IntoLua for TaggedBlob {
+    fn into_lua(self, lua: &Lua) -> Result<Value> {
+        let table = lua.create_table_with_capacity(0, 2)?;
+        table.raw_set("version", self.version)?;
+        table.raw_set("data", lua.create_string(self.data)?)?;
+        Ok(Value::Table(table))
+    }
+}
+
+impl FromLua for TaggedBlob {
+    fn from_lua(value: Value, _: &Lua) -> Result<Self> {
+        let table = match value {
+            Value::Table(table) => table,
+            _ => {
+                return Err(Error::FromLuaConversionError {
+                    from: value.type_name(),
+                    to: "TaggedBlob".to_string(),
+                    message: Some("expected table".to_string()),
+                });
+            }
+        };
+
+        let version = table.get("version")?;
+        let data = match table.get::<Value>("data")? {
+            Value::String(s) => s.as_bytes().to_vec(),
+            value => {
+                return Err(Error::FromLuaConversionError {
+                    from: value.type_name(),
+                    to: "TaggedBlob".to_string(),
+                    message: Some("expected string for `data` field".to_string()),
+                });
+            }
+        };
+        Ok(TaggedBlob { version, data })
+    }
+}
@@ -0,0 +1,109 @@
+use std::string::String as StdString;
+
+use crate::error::{Error, Result};
+use crate::state::Lua;
+use crate::table::Table;
+use crate::traits::{FromLua, IntoLua};
+use crate::types::hex::decode_hex;
+use crate::value::Value;
+
+/// An RGBA color with 8-bit channels.
+///
+/// [`FromLua`] accepts any of the following representations:
+/// - a table with named fields `{ r = 255, g = 128, b = 0, a = 255 }`
+/// - a sequence `{ 255, 128, 0, 255 }`
+/// - a hex string `"#RRGGBBAA"` or `"#RRGGBB"`
+///
+/// The alpha channel is optional in every form and defaults to `255` (opaque). Every channel must
+/// be in the range `0..=255`.
+///
+/// [`IntoLua`] emits a table with named fields.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    pub a: u8,
+}
+
+impl Color {
+    /// Creates an opaque color from its red, green and blue channels.
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Color { r, g, b, a: 255 }
+    }
+
+    /// Creates a color from its red, green, blue and alpha channels.
+    pub const fn rgba(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Color { r, g, b, a }
+    }
+
+    fn from_table(table: &Table) -> Result<Self> {
+        if table.contains_key("r")? {
+            return Ok(Color {
+                r: table.get("r")?,
+                g: table.get("g")?,
+                b: table.get("b")?,
+                a: table.get::<Option<u8>>("a")?.unwrap_or(255),
+            });
+        }
+
+        let len = table.raw_len();
+        if !(3..=4).contains(&len) {
+            return Err(conv_err("table", format!("expected 3 or 4 channels, got {len}")));
+        }
+        Ok(Color {
+            r: table.get(1)?,
+            g: table.get(2)?,
+            b: table.get(3)?,
+            a: table.get::<Option<u8>>(4)?.unwrap_or(255),
+        })
+    }
+
+    fn from_hex(s: &[u8]) -> Result<Self> {
+        let digits = match s.strip_prefix(b"#") {
+            Some(digits) => digits,
+            None => return Err(conv_err("string", "hex color must start with '#'".to_string())),
+        };
+        let mut bytes = [0, 0, 0, 255];
+        match digits.len() {
+            6 => decode_hex(digits, &mut bytes[..3]),
+            8 => decode_hex(digits, &mut bytes),
+            n => return Err(conv_err("string", format!("expected 6 or 8 hex digits, got {n}"))),
+        }
+        .map_err(|err| conv_err("string", err))?;
+        let [r, g, b, a] = bytes;
+        Ok(Color { r, g, b, a })
+    }
+}
+
+impl IntoLua for Color {
+    fn into_lua(self, lua: &Lua) -> Result<Value> {
+        let table = lua.create_table_with_capacity(0, 4)?;
+        table.raw_set("r", self.r)?;
+        table.raw_set("g", self.g)?;
+        table.raw_set("b", self.b)?;
+        table.raw_set("a", self.a)?;
+        Ok(Value::Table(table))
+    }
+}
+
+impl FromLua for Color {
+    fn from_lua(value: Value, _: &Lua) -> Result<Self> {
+        match value {
+            Value::Table(table) => Color::from_table(&table),
+            Value::String(s) => Color::from_hex(&s.as_bytes()),
+            _ => Err(conv_err(
+                value.type_name(),
+                "expected table or hex string".to_string(),
+            )),
+        }
+    }
+}
+
+fn conv_err(from: &'static str, message: StdString) -> Error {
+    Error::FromLuaConversionError {
+        from,
+        to: "Color".to_string(),
+        message: Some(message),
+    }
+}
@@ -0,0 +1,115 @@
+use crate::error::{Error, Result};
+use crate::state::Lua;
+use crate::traits::{FromLua, ShortTypeName as _};
+use crate::value::Value;
+
+/// A trait for bit-packed flag types that can be read from a Lua table of booleans.
+///
+/// Implementing this trait allows reading values like `{ read = true, write = false }` into a Rust
+/// bitmask using the [`Flags`] wrapper.
+///
+/// # Examples
+///
+/// ```
+/// # use mlua::{FlagTable, Flags, Lua, Result};
+/// # fn main() -> Result<()> {
+/// #[derive(Debug, PartialEq)]
+/// struct Permissions(u8);
+///
+/// impl FlagTable for Permissions {
+///     fn flag_bit(name: &str) -> Option<u32> {
+///         match name {
+///             "read" => Some(0),
+///             "write" => Some(1),
+///             "exec" => Some(2),
+///             _ => None,
+///         }
+///     }
+///
+///     fn from_bits(bits: u64) -> Self {
+///         Permissions(bits as u8)
+///     }
+/// }
+///
+/// let lua = Lua::new();
+/// let Flags(perms) = lua.load("{ read = true, exec = true }").eval::<Flags<Permissions>>()?;
+/// assert_eq!(perms, Permissions(0b101));
+/// # Ok(())
+/// # }
+/// ```
+pub trait FlagTable: Sized {
+    /// Whether unknown flag names should be rejected.
+    ///
+    /// By default unknown keys are silently ignored.
+    const DENY_UNKNOWN_FLAGS: bool = false;
+
+    /// Returns the bit position for the flag `name` or `None` if the flag is unknown.
+    ///
+    /// Bit positions must be less than 64.
+    fn flag_bit(name: &str) -> Option<u32>;
+
+    /// Constructs the flags value from the collected bitmask.
+    fn from_bits(bits: u64) -> Self;
+}
+
+/// Wraps a [`FlagTable`] type to read it from a Lua table of booleans.
+///
+/// Every key set to `true` sets the corresponding bit; keys set to `false` are ignored.
+/// Values that are not booleans produce a conversion error.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Flags<T>(pub T);
+
+impl<T: FlagTable> FromLua for Flags<T> {
+    fn from_lua(value: Value, _: &Lua) -> Result<Self> {
+        let table = match value {
+            Value::Table(table) => table,
+            _ => {
+                return Err(Error::FromLuaConversionError {
+                    from: value.type_name(),
+                    to: T::type_name(),
+                    message: Some("expected table".to_string()),
+                });
+            }
+        };
+
+        let mut bits = 0u64;
+        for pair in table.pairs::<Value, Value>() {
+            let (key, val) = pair?;
+            let bit = match key.as_string().and_then(|s| s.to_str().ok()) {
+                Some(name) => T::flag_bit(&name),
+                None => None,
+            };
+            let bit = match bit {
+                Some(bit) if bit < u64::BITS => bit,
+                Some(bit) => {
+                    return Err(Error::FromLuaConversionError {
+                        from: "table",
+                        to: T::type_name(),
+                        message: Some(format!("flag bit {bit} is out of range")),
+                    });
+                }
+                None if T::DENY_UNKNOWN_FLAGS => {
+                    return Err(Error::FromLuaConversionError {
+                        from: "table",
+                        to: T::type_name(),
+                        message: Some(format!("unknown flag {}", key.to_string()?)),
+                    });
+                }
+                None => continue,
+            };
+            match val {
+                Value::Boolean(true) => bits |= 1 << bit,
+                Value::Boolean(false) => {}
+                _ => {
+                    return Err(Error::FromLuaConversionError {
+                        from: val.type_name(),
+                        to: T::type_name(),
+                        message: Some(format!("expected boolean for flag {}", key.to_string()?)),
+                    });
+                }
+            }
+        }
+
+        Ok(Flags(T::from_bits(bits)))
+    }
+}
@@ -0,0 +1,103 @@
+use std::result::Result as StdResult;
+use std::string::String as StdString;
+
+use crate::error::{Error, Result};
+use crate::state::Lua;
+use crate::table::Table;
+use crate::traits::{FromLua, IntoLua};
+use crate::value::Value;
+
+/// A geographic coordinate in degrees.
+///
+/// [`FromLua`] accepts any of the following representations:
+/// - a table with named fields `{ lat = 51.5, lon = -0.12 }`
+/// - a sequence `{ 51.5, -0.12 }`
+/// - a string `"51.5,-0.12"`
+///
+/// The latitude must be in the range `-90.0..=90.0` and the longitude in the range
+/// `-180.0..=180.0`.
+///
+/// [`IntoLua`] emits a table with named fields.
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+pub struct LatLon {
+    pub lat: f64,
+    pub lon: f64,
+}
+
+impl LatLon {
+    /// Creates a new coordinate.
+    ///
+    /// Returns a runtime error if the latitude or longitude is out of range.
+    pub fn new(lat: f64, lon: f64) -> Result<Self> {
+        check_coords(lat, lon).map_err(Error::runtime)?;
+        Ok(LatLon { lat, lon })
+    }
+
+    fn from_table(table: &Table) -> Result<Self> {
+        let (lat, lon) = if table.contains_key("lat")? {
+            (table.get("lat")?, table.get("lon")?)
+        } else {
+            let len = table.raw_len();
+            if len != 2 {
+                return Err(conv_err("table", format!("expected 2 coordinates, got {len}")));
+            }
+            (table.get(1)?, table.get(2)?)
+        };
+        check_coords(lat, lon).map_err(|msg| conv_err("table", msg))?;
+        Ok(LatLon { lat, lon })
+    }
+
+    fn from_str(s: &str) -> Result<Self> {
+        let parse = |s: &str| {
+            let s = s.trim();
+            s.parse::<f64>()
+                .map_err(|_| conv_err("string", format!("invalid coordinate {s:?}")))
+        };
+        let (lat, lon) = match s.split_once(',') {
+            Some((lat, lon)) => (parse(lat)?, parse(lon)?),
+            None => return Err(conv_err("string", "expected \"lat,lon\" string".to_string())),
+        };
+        check_coords(lat, lon).map_err(|msg| conv_err("string", msg))?;
+        Ok(LatLon { lat, lon })
+    }
+}
+
+impl IntoLua for LatLon {
+    fn into_lua(self, lua: &Lua) -> Result<Value> {
+        let table = lua.create_table_with_capacity(0, 2)?;
+        table.raw_set("lat", self.lat)?;
+        table.raw_set("lon", self.lon)?;
+        Ok(Value::Table(table))
+    }
+}
+
+impl FromLua for LatLon {
+    fn from_lua(value: Value, _: &Lua) -> Result<Self> {
+        match value {
+            Value::Table(table) => LatLon::from_table(&table),
+            Value::String(s) => LatLon::from_str(&s.to_str()?),
+            _ => Err(conv_err(
+                value.type_name(),
+                "expected table or string".to_string(),
+            )),
+        }
+    }
+}
+
+fn check_coords(lat: f64, lon: f64) -> StdResult<(), StdString> {
+    if !(-90.0..=90.0).contains(&lat) {
+        return Err(format!("latitude {lat} is out of range -90..=90"));
+    }
+    if !(-180.0..=180.0).contains(&lon) {
+        return Err(format!("longitude {lon} is out of range -180..=180"));
+    }
+    Ok(())
+}
+
+fn conv_err(from: &'static str, message: StdString) -> Error {
+    Error::FromLuaConversionError {
+        from,
+        to: "LatLon".to_string(),
+        message: Some(message),
+    }
+}
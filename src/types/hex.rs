@@ -0,0 +1,152 @@
+use std::fmt::Write as _;
+use std::result::Result as StdResult;
+use std::string::String as StdString;
+
+use crate::error::{Error, Result};
+use crate::state::Lua;
+use crate::traits::{FromLua, IntoLua};
+use crate::value::Value;
+
+/// A 16-byte identifier (eg. UUID) represented in Lua as a hex string.
+///
+/// [`IntoLua`] emits a 32-character lowercase hex string. [`FromLua`] accepts a hex string in
+/// either case, optionally containing hyphens (eg. `"67e55044-10b1-426f-9247-bb680e5fe0c8"`).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Hex16(pub [u8; 16]);
+
+impl IntoLua for Hex16 {
+    #[inline]
+    fn into_lua(self, lua: &Lua) -> Result<Value> {
+        Ok(Value::String(lua.create_string(encode_hex(&self.0))?))
+    }
+}
+
+impl FromLua for Hex16 {
+    fn from_lua(value: Value, _: &Lua) -> Result<Self> {
+        let ty = value.type_name();
+        let conv_err = |message: StdString| Error::FromLuaConversionError {
+            from: ty,
+            to: "Hex16".to_string(),
+            message: Some(message),
+        };
+        let s = match value {
+            Value::String(s) => s,
+            _ => return Err(conv_err("expected string".to_string())),
+        };
+        let digits = s
+            .as_bytes()
+            .iter()
+            .copied()
+            .filter(|&b| b != b'-')
+            .collect::<Vec<_>>();
+        if digits.len() != 32 {
+            return Err(conv_err(format!("expected 32 hex digits, got {}", digits.len())));
+        }
+        let mut bytes = [0; 16];
+        decode_hex(&digits, &mut bytes).map_err(conv_err)?;
+        Ok(Hex16(bytes))
+    }
+}
+
+/// A fixed-size content hash (eg. SHA-256 digest).
+///
+/// [`IntoLua`] emits the raw `N` bytes as a Lua string. To pass the hash to Lua in hex form
+/// instead, use [`Digest::to_hex`].
+///
+/// [`FromLua`] accepts either an `N`-byte raw string or a `2N`-character hex string (in either
+/// case), telling them apart by length.
+///
+/// # Examples
+///
+/// ```
+/// # use mlua::{Digest, Lua, Result};
+/// # fn main() -> Result<()> {
+/// let lua = Lua::new();
+/// let digest = Digest([0xab; 4]);
+/// lua.globals().set("raw", digest)?;
+/// lua.globals().set("hex", digest.to_hex())?;
+/// assert_eq!(lua.load("#raw").eval::<usize>()?, 4);
+/// assert_eq!(lua.load("hex").eval::<String>()?, "abababab");
+/// assert_eq!(lua.load("hex").eval::<Digest<4>>()?, digest);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Digest<const N: usize>(pub [u8; N]);
+
+impl<const N: usize> Digest<N> {
+    /// Returns the digest as a lowercase hex string.
+    pub fn to_hex(&self) -> StdString {
+        encode_hex(&self.0)
+    }
+}
+
+impl<const N: usize> Default for Digest<N> {
+    fn default() -> Self {
+        Digest([0; N])
+    }
+}
+
+impl<const N: usize> IntoLua for Digest<N> {
+    #[inline]
+    fn into_lua(self, lua: &Lua) -> Result<Value> {
+        Ok(Value::String(lua.create_string(self.0)?))
+    }
+}
+
+impl<const N: usize> FromLua for Digest<N> {
+    fn from_lua(value: Value, _: &Lua) -> Result<Self> {
+        let ty = value.type_name();
+        let conv_err = |message: StdString| Error::FromLuaConversionError {
+            from: ty,
+            to: format!("Digest<{N}>"),
+            message: Some(message),
+        };
+        let s = match value {
+            Value::String(s) => s,
+            _ => return Err(conv_err("expected string".to_string())),
+        };
+        let bytes = s.as_bytes();
+        let mut digest = [0; N];
+        if bytes.len() == N {
+            digest.copy_from_slice(&bytes);
+        } else if bytes.len() == N * 2 {
+            decode_hex(&bytes, &mut digest).map_err(conv_err)?;
+        } else {
+            let len = bytes.len();
+            return Err(conv_err(format!(
+                "expected {N} bytes or {} hex digits, got {len} bytes",
+                N * 2
+            )));
+        }
+        Ok(Digest(digest))
+    }
+}
+
+pub(crate) fn encode_hex(bytes: &[u8]) -> StdString {
+    let mut s = StdString::with_capacity(bytes.len() * 2);
+    for b in bytes {
+        let _ = write!(s, "{b:02x}");
+    }
+    s
+}
+
+/// Decodes hex `digits` into `out`. The number of digits must be exactly twice the `out` length.
+pub(crate) fn decode_hex(digits: &[u8], out: &mut [u8]) -> StdResult<(), StdString> {
+    debug_assert_eq!(digits.len(), out.len() * 2);
+    for (i, pair) in digits.chunks_exact(2).enumerate() {
+        let hi = hex_digit(pair[0])?;
+        let lo = hex_digit(pair[1])?;
+        out[i] = (hi << 4) | lo;
+    }
+    Ok(())
+}
+
+fn hex_digit(c: u8) -> StdResult<u8, StdString> {
+    match c {
+        b'0'..=b'9' => Ok(c - b'0'),
+        b'a'..=b'f' => Ok(c - b'a' + 10),
+        b'A'..=b'F' => Ok(c - b'A' + 10),
+        _ => Err(format!("invalid hex character {:?}", c as char)),
+    }
+}
@@ -0,0 +1,100 @@
+use std::collections::hash_map::RandomState;
+use std::collections::HashMap;
+use std::hash::BuildHasher;
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+use rustc_hash::FxHashSet;
+
+use crate::error::{Error, Result};
+use crate::state::Lua;
+use crate::string::String as LuaString;
+use crate::traits::{FromLua, ShortTypeName as _};
+use crate::value::Value;
+
+/// A per-[`Lua`] cache of strings.
+///
+/// Interning the same string multiple times returns clones of the same [`Arc<str>`], so repeated
+/// keys share one allocation. The interner can be obtained using [`Lua::string_interner`] and is
+/// used by [`InternedKeys`].
+///
+/// Interned strings are kept until the interner is [cleared] or the Lua instance is dropped.
+///
+/// [cleared]: StringInterner::clear
+#[derive(Debug, Default)]
+pub struct StringInterner(Mutex<FxHashSet<Arc<str>>>);
+
+impl StringInterner {
+    /// Returns the interned copy of `s`, adding it to the cache if needed.
+    pub fn intern(&self, s: &str) -> Arc<str> {
+        let mut strings = self.0.lock();
+        if let Some(interned) = strings.get(s) {
+            return interned.clone();
+        }
+        let interned = Arc::<str>::from(s);
+        strings.insert(interned.clone());
+        interned
+    }
+
+    /// Returns the number of interned strings.
+    pub fn len(&self) -> usize {
+        self.0.lock().len()
+    }
+
+    /// Returns `true` if there are no interned strings.
+    pub fn is_empty(&self) -> bool {
+        self.0.lock().is_empty()
+    }
+
+    /// Removes all strings from the cache.
+    ///
+    /// Strings that were already handed out remain valid.
+    pub fn clear(&self) {
+        self.0.lock().clear();
+    }
+}
+
+/// A map with string keys interned using the per-[`Lua`] [`StringInterner`].
+///
+/// [`FromLua`] reads a Lua table with string keys, like [`HashMap<String, V>`], but maps read
+/// repeatedly with the same keys share one allocation for every key.
+///
+/// # Examples
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use mlua::{InternedKeys, Lua, Result};
+/// # fn main() -> Result<()> {
+/// let lua = Lua::new();
+/// let InternedKeys(a) = lua.load("{ name = 1 }").eval::<InternedKeys<i32>>()?;
+/// let InternedKeys(b) = lua.load("{ name = 2 }").eval::<InternedKeys<i32>>()?;
+/// let (key_a, key_b) = (a.keys().next().unwrap(), b.keys().next().unwrap());
+/// assert!(Arc::ptr_eq(key_a, key_b));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct InternedKeys<V, S = RandomState>(pub HashMap<Arc<str>, V, S>);
+
+impl<V: FromLua, S: BuildHasher + Default> FromLua for InternedKeys<V, S> {
+    fn from_lua(value: Value, lua: &Lua) -> Result<Self> {
+        let table = match value {
+            Value::Table(table) => table,
+            _ => {
+                return Err(Error::FromLuaConversionError {
+                    from: value.type_name(),
+                    to: Self::type_name(),
+                    message: Some("expected table".to_string()),
+                });
+            }
+        };
+
+        let interner = lua.string_interner();
+        let mut map = HashMap::with_hasher(S::default());
+        for pair in table.pairs::<LuaString, V>() {
+            let (key, value) = pair?;
+            map.insert(interner.intern(&key.to_str()?), value);
+        }
+        Ok(InternedKeys(map))
+    }
+}
@@ -0,0 +1,33 @@
+use crate::error::Result;
+use crate::state::Lua;
+use crate::traits::IntoLua;
+use crate::value::Value;
+
+/// A value that is constructed only when converted into Lua.
+///
+/// The wrapped closure is invoked exactly once, at conversion time. This allows deferring
+/// expensive work (eg. building a large table) until it is actually needed.
+///
+/// # Examples
+///
+/// ```
+/// # use mlua::{IntoLua, Lazy, Lua, Result};
+/// # fn main() -> Result<()> {
+/// let lua = Lua::new();
+/// let config = Lazy(|lua: &Lua| lua.create_sequence_from([1, 2, 3])?.into_lua(lua));
+/// lua.globals().set("config", config)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Lazy<F>(pub F);
+
+impl<F> IntoLua for Lazy<F>
+where
+    F: FnOnce(&Lua) -> Result<Value>,
+{
+    #[inline]
+    fn into_lua(self, lua: &Lua) -> Result<Value> {
+        (self.0)(lua)
+    }
+}
@@ -0,0 +1,374 @@
+use std::fmt;
+use std::ops::{Add, Div, Mul, Sub};
+use std::string::String as StdString;
+
+use num_traits::Float;
+
+use crate::error::{Error, Result};
+use crate::state::{Lua, RawLua};
+use crate::traits::{FromLua, IntoLua, ShortTypeName as _};
+use crate::types::{Integer, Number};
+use crate::value::Value;
+
+/// A percentage expressed in basis points (hundredths of a percent).
+///
+/// `BasisPoints(10000)` is 100%, `BasisPoints(1)` is 0.01%. The value is passed to Lua as an
+/// integer, which keeps financial calculations free of floating point rounding.
+///
+/// [`FromLua`] accepts integers in the range `0..=10000` and fails otherwise.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BasisPoints(pub i32);
+
+impl BasisPoints {
+    /// The maximum value (100%).
+    pub const MAX: BasisPoints = BasisPoints(10000);
+}
+
+impl IntoLua for BasisPoints {
+    #[inline]
+    fn into_lua(self, lua: &Lua) -> Result<Value> {
+        self.0.into_lua(lua)
+    }
+
+    #[inline]
+    unsafe fn push_into_stack(self, lua: &RawLua) -> Result<()> {
+        self.0.push_into_stack(lua)
+    }
+}
+
+impl FromLua for BasisPoints {
+    fn from_lua(value: Value, lua: &Lua) -> Result<Self> {
+        let ty = value.type_name();
+        let bp = i32::from_lua(value, lua)?;
+        if !(0..=Self::MAX.0).contains(&bp) {
+            return Err(Error::FromLuaConversionError {
+                from: ty,
+                to: "BasisPoints".to_string(),
+                message: Some(format!("value {bp} is out of range 0..=10000")),
+            });
+        }
+        Ok(BasisPoints(bp))
+    }
+}
+
+/// A fraction in the range `0.0..=1.0`, such as a normalized weight or a probability.
+///
+/// [`FromLua`] accepts numbers within the range and fails on out-of-range values and `NaN`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Fraction01(pub f64);
+
+impl IntoLua for Fraction01 {
+    #[inline]
+    fn into_lua(self, lua: &Lua) -> Result<Value> {
+        self.0.into_lua(lua)
+    }
+
+    #[inline]
+    unsafe fn push_into_stack(self, lua: &RawLua) -> Result<()> {
+        self.0.push_into_stack(lua)
+    }
+}
+
+impl FromLua for Fraction01 {
+    fn from_lua(value: Value, lua: &Lua) -> Result<Self> {
+        let ty = value.type_name();
+        let n = f64::from_lua(value, lua)?;
+        if !(0.0..=1.0).contains(&n) {
+            return Err(Error::FromLuaConversionError {
+                from: ty,
+                to: "Fraction01".to_string(),
+                message: Some(format!("value {n} is out of range 0.0..=1.0")),
+            });
+        }
+        Ok(Fraction01(n))
+    }
+}
+
+/// A floating point number (`f32` or `f64`) that must be finite.
+///
+/// The plain float conversions accept any Lua number, including `NaN` and infinities (eg. produced
+/// by `0/0` or `1/0`). [`FromLua`] for this type rejects them instead, so invalid values are caught
+/// at the boundary rather than silently propagated.
+///
+/// # Examples
+///
+/// ```
+/// # use mlua::{FiniteFloat, Lua, Result};
+/// # fn main() -> Result<()> {
+/// let lua = Lua::new();
+/// assert_eq!(lua.load("1 / 4").eval::<FiniteFloat<f64>>()?, FiniteFloat(0.25));
+/// assert!(lua.load("0 / 0").eval::<FiniteFloat<f64>>().is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+pub struct FiniteFloat<T>(pub T);
+
+impl<T: Float + IntoLua> IntoLua for FiniteFloat<T> {
+    #[inline]
+    fn into_lua(self, lua: &Lua) -> Result<Value> {
+        self.0.into_lua(lua)
+    }
+
+    #[inline]
+    unsafe fn push_into_stack(self, lua: &RawLua) -> Result<()> {
+        self.0.push_into_stack(lua)
+    }
+}
+
+impl<T: Float + FromLua + fmt::Display> FromLua for FiniteFloat<T> {
+    fn from_lua(value: Value, lua: &Lua) -> Result<Self> {
+        let ty = value.type_name();
+        let n = T::from_lua(value, lua)?;
+        if !n.is_finite() {
+            return Err(Error::FromLuaConversionError {
+                from: ty,
+                to: Self::type_name(),
+                message: Some(format!("expected finite number, got {n}")),
+            });
+        }
+        Ok(FiniteFloat(n))
+    }
+}
+
+/// A 0-based Rust index that is represented in Lua as a 1-based index.
+///
+/// [`FromLua`] accepts a Lua integer and subtracts 1 from it, failing on `0` and negative values.
+/// [`IntoLua`] does the opposite and adds 1.
+///
+/// # Examples
+///
+/// ```
+/// # use mlua::{Index, Lua, Result};
+/// # fn main() -> Result<()> {
+/// let lua = Lua::new();
+/// let items = vec!["a", "b", "c"];
+/// let get = lua.create_function(move |_, Index(i): Index| Ok(items.get(i).copied()))?;
+/// assert_eq!(get.call::<String>(1)?, "a");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Index(pub usize);
+
+impl IntoLua for Index {
+    #[inline]
+    fn into_lua(self, lua: &Lua) -> Result<Value> {
+        match self.0.checked_add(1) {
+            Some(i) => i.into_lua(lua),
+            None => Err(Error::ToLuaConversionError {
+                from: "Index".to_string(),
+                to: "integer",
+                message: Some("index is too large".to_string()),
+            }),
+        }
+    }
+}
+
+impl FromLua for Index {
+    fn from_lua(value: Value, lua: &Lua) -> Result<Self> {
+        let ty = value.type_name();
+        let i = Integer::from_lua(value, lua)?;
+        if i < 1 {
+            return Err(Error::FromLuaConversionError {
+                from: ty,
+                to: "Index".to_string(),
+                message: Some("Lua indices are 1-based".to_string()),
+            });
+        }
+        match usize::try_from(i - 1) {
+            Ok(i) => Ok(Index(i)),
+            Err(_) => Err(Error::FromLuaConversionError {
+                from: ty,
+                to: "Index".to_string(),
+                message: Some("out of range".to_string()),
+            }),
+        }
+    }
+}
+
+/// A Lua number that keeps track of whether it is an integer or a float.
+///
+/// [`FromLua`] accepts only Lua numbers (strings are not coerced) and preserves the subtype used by
+/// Lua, so `3` becomes [`LuaNum::Int`] and `3.0` becomes [`LuaNum::Float`].
+///
+/// Arithmetic operators follow Lua semantics: operations on two integers produce an integer
+/// (wrapping around on overflow), and mixing an integer with a float promotes the result to float.
+/// Division always produces a float.
+///
+/// Equality takes the subtype into account, so `LuaNum::Int(3) != LuaNum::Float(3.0)`. A numeric
+/// ordering would have to treat these as equal, so `LuaNum` does not implement [`PartialOrd`].
+///
+/// # Examples
+///
+/// ```
+/// # use mlua::{Lua, LuaNum, Result};
+/// # fn main() -> Result<()> {
+/// let lua = Lua::new();
+/// let n = lua.load("3").eval::<LuaNum>()?;
+/// assert_eq!(n, LuaNum::Int(3));
+/// assert_eq!(n + LuaNum::Float(0.5), LuaNum::Float(3.5));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LuaNum {
+    /// An integer number.
+    Int(Integer),
+    /// A floating point number.
+    Float(Number),
+}
+
+impl LuaNum {
+    /// Returns `true` if the number is an integer.
+    #[inline]
+    pub fn is_int(&self) -> bool {
+        matches!(self, LuaNum::Int(_))
+    }
+
+    /// Returns `true` if the number is a float.
+    #[inline]
+    pub fn is_float(&self) -> bool {
+        matches!(self, LuaNum::Float(_))
+    }
+
+    /// Returns the number as a float, converting integers if needed.
+    #[inline]
+    pub fn as_f64(&self) -> f64 {
+        match *self {
+            LuaNum::Int(i) => i as f64,
+            LuaNum::Float(n) => n,
+        }
+    }
+}
+
+impl Default for LuaNum {
+    #[inline]
+    fn default() -> Self {
+        LuaNum::Int(0)
+    }
+}
+
+impl From<Integer> for LuaNum {
+    #[inline]
+    fn from(i: Integer) -> Self {
+        LuaNum::Int(i)
+    }
+}
+
+impl From<Number> for LuaNum {
+    #[inline]
+    fn from(n: Number) -> Self {
+        LuaNum::Float(n)
+    }
+}
+
+macro_rules! impl_lua_num_op {
+    ($trait:ident, $method:ident, $int_op:ident, $op:tt) => {
+        impl $trait for LuaNum {
+            type Output = LuaNum;
+
+            #[inline]
+            fn $method(self, rhs: LuaNum) -> LuaNum {
+                match (self, rhs) {
+                    (LuaNum::Int(a), LuaNum::Int(b)) => LuaNum::Int(a.$int_op(b)),
+                    (a, b) => LuaNum::Float(a.as_f64() $op b.as_f64()),
+                }
+            }
+        }
+    };
+}
+
+impl_lua_num_op!(Add, add, wrapping_add, +);
+impl_lua_num_op!(Sub, sub, wrapping_sub, -);
+impl_lua_num_op!(Mul, mul, wrapping_mul, *);
+
+impl Div for LuaNum {
+    type Output = LuaNum;
+
+    #[inline]
+    fn div(self, rhs: LuaNum) -> LuaNum {
+        LuaNum::Float(self.as_f64() / rhs.as_f64())
+    }
+}
+
+impl IntoLua for LuaNum {
+    #[inline]
+    fn into_lua(self, _: &Lua) -> Result<Value> {
+        match self {
+            LuaNum::Int(i) => Ok(Value::Integer(i)),
+            LuaNum::Float(n) => Ok(Value::Number(n)),
+        }
+    }
+}
+
+impl FromLua for LuaNum {
+    #[inline]
+    fn from_lua(value: Value, _: &Lua) -> Result<Self> {
+        match value {
+            Value::Integer(i) => Ok(LuaNum::Int(i)),
+            Value::Number(n) => Ok(LuaNum::Float(n)),
+            _ => Err(Error::FromLuaConversionError {
+                from: value.type_name(),
+                to: "LuaNum".to_string(),
+                message: Some("expected number".to_string()),
+            }),
+        }
+    }
+}
+
+/// Either a Lua number or a Lua string, keeping the representation used by Lua.
+///
+/// The regular conversions coerce numbers and numeric strings into each other, which hides how the
+/// value was stored. [`FromLua`] for this type never coerces: a Lua number (integer or float)
+/// always becomes [`NumberOrString::Number`] and a Lua string always becomes
+/// [`NumberOrString::String`], even if it looks like a number. Any other value is an error.
+///
+/// Lua integers are stored as [`Number`], so integers with a magnitude above `2^53` lose
+/// precision. Converting the value back to Lua always produces a float, which means `math.type`
+/// reports `"float"` even if the original value was an integer. Use [`LuaNum`] to keep the
+/// subtype.
+///
+/// # Examples
+///
+/// ```
+/// # use mlua::{Lua, NumberOrString, Result};
+/// # fn main() -> Result<()> {
+/// let lua = Lua::new();
+/// assert_eq!(lua.load("5").eval::<NumberOrString>()?, NumberOrString::Number(5.0));
+/// assert_eq!(lua.load("'5'").eval::<NumberOrString>()?, NumberOrString::String("5".into()));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum NumberOrString {
+    /// A Lua number.
+    Number(Number),
+    /// A Lua string.
+    String(StdString),
+}
+
+impl IntoLua for NumberOrString {
+    #[inline]
+    fn into_lua(self, lua: &Lua) -> Result<Value> {
+        match self {
+            NumberOrString::Number(n) => Ok(Value::Number(n)),
+            NumberOrString::String(s) => s.into_lua(lua),
+        }
+    }
+}
+
+impl FromLua for NumberOrString {
+    fn from_lua(value: Value, _: &Lua) -> Result<Self> {
+        match value {
+            Value::Integer(i) => Ok(NumberOrString::Number(i as Number)),
+            Value::Number(n) => Ok(NumberOrString::Number(n)),
+            Value::String(s) => Ok(NumberOrString::String(s.to_str()?.to_owned())),
+            _ => Err(Error::FromLuaConversionError {
+                from: value.type_name(),
+                to: "NumberOrString".to_string(),
+                message: Some("expected number or string".to_string()),
+            }),
+        }
+    }
+}
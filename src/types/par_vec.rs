@@ -0,0 +1,58 @@
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+use crate::error::{Error, Result};
+use crate::state::Lua;
+use crate::traits::{FromLua, IntoLua, ShortTypeName as _};
+use crate::value::{PlainValue, Value};
+
+/// A [`Vec`] wrapper that converts elements of large Lua sequences in parallel.
+///
+/// [`FromLua`] first takes a snapshot of the sequence as [`PlainValue`]s, then converts them to
+/// `T` on the [rayon] thread pool. The conversion runs outside of the Lua state, so `T` must be
+/// [`Send`] and convertible from [`PlainValue`] without access to Lua. In practice only plain
+/// scalars (numbers and booleans) qualify. Strings are not coerced to numbers.
+///
+/// For small tables the overhead of parallelism outweighs the benefits and [`Vec<T>`] should be
+/// preferred.
+///
+/// [`IntoLua`] produces a regular Lua sequence table.
+///
+/// [rayon]: https://docs.rs/rayon
+#[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+#[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ParVec<T>(pub Vec<T>);
+
+impl<T: IntoLua> IntoLua for ParVec<T> {
+    #[inline]
+    fn into_lua(self, lua: &Lua) -> Result<Value> {
+        self.0.into_lua(lua)
+    }
+}
+
+impl<T> FromLua for ParVec<T>
+where
+    T: TryFrom<PlainValue, Error = Error> + Send,
+{
+    fn from_lua(value: Value, _: &Lua) -> Result<Self> {
+        let table = match value {
+            Value::Table(table) => table,
+            _ => {
+                return Err(Error::FromLuaConversionError {
+                    from: value.type_name(),
+                    to: Self::type_name(),
+                    message: Some("expected table".to_string()),
+                });
+            }
+        };
+
+        let mut values = Vec::with_capacity(table.raw_len());
+        for value in table.sequence_values::<Value>() {
+            values.push(value?.to_plain()?);
+        }
+        values
+            .into_par_iter()
+            .map(T::try_from)
+            .collect::<Result<Vec<_>>>()
+            .map(ParVec)
+    }
+}
@@ -0,0 +1,124 @@
+use std::string::String as StdString;
+
+use crate::error::{Error, Result};
+use crate::state::Lua;
+use crate::traits::{FromLua, IntoLua};
+use crate::value::Value;
+
+/// A numeric value with a unit of measurement.
+///
+/// [`FromLua`] reads a table `{ value = 1.5, unit = "km" }`, where `unit` must be a non-empty
+/// string. [`IntoLua`] emits the same table.
+///
+/// The unit is not validated when reading from Lua, but [`Quantity::convert_to`] only knows
+/// about a small set of built-in length, time and mass units.
+///
+/// # Examples
+///
+/// ```
+/// # use mlua::{Lua, Quantity, Result};
+/// # fn main() -> Result<()> {
+/// let lua = Lua::new();
+/// let distance = lua.load("{ value = 1.5, unit = 'km' }").eval::<Quantity>()?;
+/// assert_eq!(distance.convert_to("m")?, Quantity::new(1500.0, "m"));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, PartialOrd)]
+pub struct Quantity {
+    pub value: f64,
+    pub unit: StdString,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Dimension {
+    Length,
+    Time,
+    Mass,
+}
+
+/// Known units with their dimension and scale relative to the base unit (m, s, kg).
+const UNITS: &[(&str, Dimension, f64)] = &[
+    ("mm", Dimension::Length, 0.001),
+    ("cm", Dimension::Length, 0.01),
+    ("m", Dimension::Length, 1.0),
+    ("km", Dimension::Length, 1000.0),
+    ("in", Dimension::Length, 0.0254),
+    ("ft", Dimension::Length, 0.3048),
+    ("mi", Dimension::Length, 1609.344),
+    ("ms", Dimension::Time, 0.001),
+    ("s", Dimension::Time, 1.0),
+    ("min", Dimension::Time, 60.0),
+    ("h", Dimension::Time, 3600.0),
+    ("mg", Dimension::Mass, 0.000001),
+    ("g", Dimension::Mass, 0.001),
+    ("kg", Dimension::Mass, 1.0),
+    ("lb", Dimension::Mass, 0.45359237),
+];
+
+impl Quantity {
+    /// Creates a new quantity.
+    pub fn new(value: f64, unit: impl Into<StdString>) -> Self {
+        Quantity {
+            value,
+            unit: unit.into(),
+        }
+    }
+
+    /// Converts the quantity into another unit of the same dimension.
+    ///
+    /// Supported units are `mm`, `cm`, `m`, `km`, `in`, `ft`, `mi` (length), `ms`, `s`, `min`, `h`
+    /// (time) and `mg`, `g`, `kg`, `lb` (mass).
+    ///
+    /// Returns an error if either unit is unknown or the units have different dimensions.
+    pub fn convert_to(&self, unit: &str) -> Result<Quantity> {
+        let lookup = |unit: &str| {
+            UNITS
+                .iter()
+                .find(|(name, ..)| *name == unit)
+                .map(|&(_, dim, scale)| (dim, scale))
+                .ok_or_else(|| Error::runtime(format!("unknown unit `{unit}`")))
+        };
+        let (from_dim, from_scale) = lookup(&self.unit)?;
+        let (to_dim, to_scale) = lookup(unit)?;
+        if from_dim != to_dim {
+            return Err(Error::runtime(format!(
+                "cannot convert `{}` to `{unit}`: incompatible units",
+                self.unit
+            )));
+        }
+        Ok(Quantity::new(self.value * from_scale / to_scale, unit))
+    }
+}
+
+impl IntoLua for Quantity {
+    fn into_lua(self, lua: &Lua) -> Result<Value> {
+        let table = lua.create_table_with_capacity(0, 2)?;
+        table.raw_set("value", self.value)?;
+        table.raw_set("unit", self.unit)?;
+        Ok(Value::Table(table))
+    }
+}
+
+impl FromLua for Quantity {
+    fn from_lua(value: Value, _: &Lua) -> Result<Self> {
+        let ty = value.type_name();
+        let conv_err = |message: &str| Error::FromLuaConversionError {
+            from: ty,
+            to: "Quantity".to_string(),
+            message: Some(message.to_string()),
+        };
+        let table = match value {
+            Value::Table(table) => table,
+            _ => return Err(conv_err("expected table")),
+        };
+        let unit = match table.get::<Value>("unit")? {
+            Value::String(s) if !s.as_bytes().is_empty() => s.to_str()?.to_string(),
+            _ => return Err(conv_err("`unit` must be a non-empty string")),
+        };
+        Ok(Quantity {
+            value: table.get("value")?,
+            unit,
+        })
+    }
+}
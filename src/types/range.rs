@@ -0,0 +1,108 @@
+use std::ops::{Bound, RangeBounds};
+
+use crate::error::{Error, Result};
+use crate::state::Lua;
+use crate::traits::FromLua;
+use crate::types::StrictBool;
+use crate::value::Value;
+
+/// A range of keys for querying ordered collections such as [`BTreeMap`].
+///
+/// [`FromLua`] reads a table with the following optional fields:
+/// - `lo`: the lower bound (unbounded if absent)
+/// - `hi`: the upper bound (unbounded if absent)
+/// - `lo_inclusive`: whether the lower bound is included (`true` by default)
+/// - `hi_inclusive`: whether the upper bound is included (`false` by default)
+///
+/// The `lo_inclusive` and `hi_inclusive` flags must be booleans if present.
+///
+/// The defaults match Rust's `lo..hi` range. If both bounds are present, `lo` must not be greater
+/// than `hi`, and they cannot be equal when both are exclusive.
+///
+/// `RangeQuery` implements [`RangeBounds`] and can be passed directly to [`BTreeMap::range`].
+///
+/// # Examples
+///
+/// ```
+/// # use std::collections::BTreeMap;
+/// # use mlua::{Lua, RangeQuery, Result};
+/// # fn main() -> Result<()> {
+/// let lua = Lua::new();
+/// let map = BTreeMap::from([(1, "a"), (2, "b"), (3, "c"), (4, "d")]);
+/// let query = lua.load("{ lo = 2, hi = 3, hi_inclusive = true }").eval::<RangeQuery<i32>>()?;
+/// let values = map.range(query).map(|(_, v)| *v).collect::<Vec<_>>();
+/// assert_eq!(values, vec!["b", "c"]);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`BTreeMap`]: std::collections::BTreeMap
+/// [`BTreeMap::range`]: std::collections::BTreeMap::range
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RangeQuery<K> {
+    pub lo: Bound<K>,
+    pub hi: Bound<K>,
+}
+
+impl<K> RangeBounds<K> for RangeQuery<K> {
+    fn start_bound(&self) -> Bound<&K> {
+        self.lo.as_ref()
+    }
+
+    fn end_bound(&self) -> Bound<&K> {
+        self.hi.as_ref()
+    }
+}
+
+impl<K: FromLua + PartialOrd> FromLua for RangeQuery<K> {
+    fn from_lua(value: Value, _: &Lua) -> Result<Self> {
+        let table = match value {
+            Value::Table(table) => table,
+            _ => {
+                return Err(Error::FromLuaConversionError {
+                    from: value.type_name(),
+                    to: "RangeQuery".to_string(),
+                    message: Some("expected table".to_string()),
+                });
+            }
+        };
+
+        let StrictBool(lo_inclusive) = table
+            .get::<Option<StrictBool>>("lo_inclusive")?
+            .unwrap_or(StrictBool(true));
+        let StrictBool(hi_inclusive) = table
+            .get::<Option<StrictBool>>("hi_inclusive")?
+            .unwrap_or(StrictBool(false));
+        let lo = match table.get::<Option<K>>("lo")? {
+            Some(lo) if lo_inclusive => Bound::Included(lo),
+            Some(lo) => Bound::Excluded(lo),
+            None => Bound::Unbounded,
+        };
+        let hi = match table.get::<Option<K>>("hi")? {
+            Some(hi) if hi_inclusive => Bound::Included(hi),
+            Some(hi) => Bound::Excluded(hi),
+            None => Bound::Unbounded,
+        };
+
+        let message = match (&lo, &hi) {
+            (Bound::Included(lo) | Bound::Excluded(lo), Bound::Included(hi) | Bound::Excluded(hi))
+                if lo > hi =>
+            {
+                Some("lower bound is greater than upper bound")
+            }
+            (Bound::Excluded(lo), Bound::Excluded(hi)) if lo == hi => {
+                Some("bounds are equal and both exclusive")
+            }
+            _ => None,
+        };
+        if let Some(message) = message {
+            return Err(Error::FromLuaConversionError {
+                from: "table",
+                to: "RangeQuery".to_string(),
+                message: Some(message.to_string()),
+            });
+        }
+
+        Ok(RangeQuery { lo, hi })
+    }
+}
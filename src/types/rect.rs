@@ -0,0 +1,92 @@
+use std::result::Result as StdResult;
+use std::string::String as StdString;
+
+use crate::error::{Error, Result};
+use crate::state::Lua;
+use crate::table::Table;
+use crate::traits::{FromLua, IntoLua};
+use crate::value::Value;
+
+/// An axis-aligned rectangle, such as the bounds of a UI element.
+///
+/// [`FromLua`] accepts either a table with named fields `{ x = 0, y = 0, w = 100, h = 50 }` or a
+/// sequence `{ 0, 0, 100, 50 }`. The width and height must not be negative.
+///
+/// [`IntoLua`] emits a table with named fields.
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Rect {
+    /// The x coordinate of the origin corner.
+    pub x: f64,
+    /// The y coordinate of the origin corner.
+    pub y: f64,
+    /// The width, not negative unless the field was modified directly.
+    pub w: f64,
+    /// The height, not negative unless the field was modified directly.
+    pub h: f64,
+}
+
+impl Rect {
+    /// Creates a new rectangle.
+    ///
+    /// Returns a runtime error if the width or height is negative or NaN.
+    pub fn new(x: f64, y: f64, w: f64, h: f64) -> Result<Self> {
+        check_size(w, h).map_err(Error::runtime)?;
+        Ok(Rect { x, y, w, h })
+    }
+
+    fn from_table(table: &Table) -> Result<Self> {
+        let (x, y, w, h) = if table.contains_key("x")? {
+            (table.get("x")?, table.get("y")?, table.get("w")?, table.get("h")?)
+        } else {
+            let len = table.raw_len();
+            if len != 4 {
+                return Err(conv_err(format!("expected 4 numbers, got {len}")));
+            }
+            (table.get(1)?, table.get(2)?, table.get(3)?, table.get(4)?)
+        };
+        check_size(w, h).map_err(conv_err)?;
+        Ok(Rect { x, y, w, h })
+    }
+}
+
+impl IntoLua for Rect {
+    fn into_lua(self, lua: &Lua) -> Result<Value> {
+        let table = lua.create_table_with_capacity(0, 4)?;
+        table.raw_set("x", self.x)?;
+        table.raw_set("y", self.y)?;
+        table.raw_set("w", self.w)?;
+        table.raw_set("h", self.h)?;
+        Ok(Value::Table(table))
+    }
+}
+
+impl FromLua for Rect {
+    fn from_lua(value: Value, _: &Lua) -> Result<Self> {
+        match value {
+            Value::Table(table) => Rect::from_table(&table),
+            _ => Err(Error::FromLuaConversionError {
+                from: value.type_name(),
+                to: "Rect".to_string(),
+                message: Some("expected table".to_string()),
+            }),
+        }
+    }
+}
+
+fn check_size(w: f64, h: f64) -> StdResult<(), StdString> {
+    if w.is_nan() || w < 0.0 {
+        return Err(format!("width {w} must not be negative"));
+    }
+    if h.is_nan() || h < 0.0 {
+        return Err(format!("height {h} must not be negative"));
+    }
+    Ok(())
+}
+
+fn conv_err(message: StdString) -> Error {
+    Error::FromLuaConversionError {
+        from: "table",
+        to: "Rect".to_string(),
+        message: Some(message),
+    }
+}
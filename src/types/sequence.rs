@@ -0,0 +1,160 @@
+use std::collections::HashMap;
+
+use crate::error::{Error, Result};
+use crate::function::Function;
+use crate::state::Lua;
+use crate::traits::{FromLua, IntoLua, ObjectLike, ShortTypeName as _};
+use crate::types::Integer;
+use crate::userdata::MetaMethod;
+use crate::value::Value;
+
+/// A [`Vec`] wrapper that can also be read from array-like userdata.
+///
+/// In addition to plain Lua tables (read the same way as [`Vec<T>`]), [`FromLua`] accepts userdata
+/// that implements the `__len` metamethod. The elements are read by indexing the userdata (using
+/// its `__index` metamethod) at positions `1..=len`.
+///
+/// [`IntoLua`] produces a regular Lua sequence table.
+///
+/// # Examples
+///
+/// ```
+/// # use mlua::{Lua, MetaMethod, Result, Sequence, UserData, UserDataMethods};
+/// # fn main() -> Result<()> {
+/// struct Range(i64);
+///
+/// impl UserData for Range {
+///     fn add_methods<M: UserDataMethods<Self>>(methods: &mut M) {
+///         methods.add_meta_method(MetaMethod::Len, |_, this, ()| Ok(this.0));
+///         methods.add_meta_method(MetaMethod::Index, |_, _, i: i64| Ok(i * 10));
+///     }
+/// }
+///
+/// let lua = Lua::new();
+/// let Sequence(v) = lua.convert::<Sequence<i64>>(Range(3))?;
+/// assert_eq!(v, vec![10, 20, 30]);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Sequence<T>(pub Vec<T>);
+
+impl<T: IntoLua> IntoLua for Sequence<T> {
+    #[inline]
+    fn into_lua(self, lua: &Lua) -> Result<Value> {
+        self.0.into_lua(lua)
+    }
+}
+
+impl<T: FromLua> FromLua for Sequence<T> {
+    fn from_lua(value: Value, lua: &Lua) -> Result<Self> {
+        match value {
+            Value::UserData(ud) => {
+                let mt = ud.metatable()?;
+                if !mt.contains(MetaMethod::Len)? {
+                    return Err(Error::FromLuaConversionError {
+                        from: "userdata",
+                        to: Self::type_name(),
+                        message: Some("userdata does not implement `__len` metamethod".to_string()),
+                    });
+                }
+                let len = mt.get::<Function>(MetaMethod::Len)?.call::<Integer>(&ud)?;
+                (1..=len)
+                    .map(|i| ud.get::<T>(i))
+                    .collect::<Result<_>>()
+                    .map(Sequence)
+            }
+            value => Vec::<T>::from_lua(value, lua).map(Sequence),
+        }
+    }
+}
+
+/// A wrapper that converts any iterator into a Lua sequence table.
+///
+/// The table is built directly from the iterator, without collecting it into a [`Vec`] first.
+/// The lower bound of [`Iterator::size_hint`] is used to preallocate the array part of the table.
+/// An empty iterator produces an empty table.
+///
+/// # Examples
+///
+/// ```
+/// # use mlua::{Lua, Result, SeqIter};
+/// # fn main() -> Result<()> {
+/// let lua = Lua::new();
+/// lua.globals().set("xs", SeqIter((1..=100).map(|i| i * i)))?;
+/// assert_eq!(lua.load("#xs").eval::<i64>()?, 100);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SeqIter<I>(pub I);
+
+impl<I> IntoLua for SeqIter<I>
+where
+    I: IntoIterator,
+    I::Item: IntoLua,
+{
+    #[inline]
+    fn into_lua(self, lua: &Lua) -> Result<Value> {
+        Ok(Value::Table(lua.create_sequence_from(self.0)?))
+    }
+}
+
+/// A sparse array represented in Lua as a table with holes.
+///
+/// The map keys are 1-based Lua indices. [`IntoLua`] sets only the present indices, so all other
+/// positions are `nil` in the resulting table. [`FromLua`] reads back exactly the present indices
+/// and fails if the table has keys that are not positive integers.
+///
+/// Note that the length operator (`#`) is not well defined for tables with holes.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct SparseArray<T>(pub HashMap<usize, T>);
+
+impl<T: IntoLua> IntoLua for SparseArray<T> {
+    fn into_lua(self, lua: &Lua) -> Result<Value> {
+        let table = lua.create_table_with_capacity(0, self.0.len())?;
+        for (i, value) in self.0 {
+            if i == 0 {
+                return Err(Error::ToLuaConversionError {
+                    from: "SparseArray".to_string(),
+                    to: "table",
+                    message: Some("Lua indices are 1-based".to_string()),
+                });
+            }
+            table.raw_set(i, value)?;
+        }
+        Ok(Value::Table(table))
+    }
+}
+
+impl<T: FromLua> FromLua for SparseArray<T> {
+    fn from_lua(value: Value, _: &Lua) -> Result<Self> {
+        let table = match value {
+            Value::Table(table) => table,
+            _ => {
+                return Err(Error::FromLuaConversionError {
+                    from: value.type_name(),
+                    to: Self::type_name(),
+                    message: Some("expected table".to_string()),
+                })
+            }
+        };
+        let mut map = HashMap::new();
+        for pair in table.pairs::<Value, T>() {
+            let (key, value) = pair?;
+            let index = match key {
+                Value::Integer(i) if i >= 1 => usize::try_from(i).ok(),
+                _ => None,
+            };
+            let Some(index) = index else {
+                return Err(Error::FromLuaConversionError {
+                    from: "table",
+                    to: Self::type_name(),
+                    message: Some(format!("invalid array index {key:?}")),
+                });
+            };
+            map.insert(index, value);
+        }
+        Ok(SparseArray(map))
+    }
+}
@@ -0,0 +1,26 @@
+use std::sync::Arc;
+
+use crate::error::Result;
+use crate::state::Lua;
+use crate::traits::IntoLua;
+use crate::value::Value;
+
+/// A shared value that is copied into Lua at conversion time.
+///
+/// [`IntoLua`] clones the contents of the [`Arc`] and converts the clone into a fresh Lua value.
+/// The Lua side gets an independent copy: later changes to the shared Rust value (eg. through
+/// interior mutability or [`Arc::make_mut`]) are not visible to Lua, and changes made by Lua are
+/// not visible to Rust.
+///
+/// To share state with Lua by reference, use [`UserData`] instead.
+///
+/// [`UserData`]: crate::UserData
+#[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Snapshot<T>(pub Arc<T>);
+
+impl<T: IntoLua + Clone> IntoLua for Snapshot<T> {
+    #[inline]
+    fn into_lua(self, lua: &Lua) -> Result<Value> {
+        T::clone(&self.0).into_lua(lua)
+    }
+}
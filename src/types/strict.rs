@@ -0,0 +1,155 @@
+use std::string::String as StdString;
+
+use num_traits::{cast, NumCast};
+
+use crate::error::{Error, Result};
+use crate::state::{Lua, RawLua};
+use crate::traits::{FromLua, IntoLua, ShortTypeName as _};
+use crate::value::Value;
+
+/// A [`bool`] wrapper with strict [`FromLua`] conversion.
+///
+/// Unlike [`bool`], which treats every value except `nil` and `false` as `true`, this type only
+/// accepts Lua booleans. `nil` is accepted as `false`, so optional flags can be omitted. Any other
+/// value results in a conversion error.
+///
+/// # Examples
+///
+/// ```
+/// # use mlua::{Lua, Result, StrictBool};
+/// # fn main() -> Result<()> {
+/// let lua = Lua::new();
+/// let f = lua.create_function(|_, StrictBool(b): StrictBool| Ok(b))?;
+/// assert!(f.call::<bool>(true)?);
+/// assert!(f.call::<bool>("yes").is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct StrictBool(pub bool);
+
+impl IntoLua for StrictBool {
+    #[inline]
+    fn into_lua(self, lua: &Lua) -> Result<Value> {
+        self.0.into_lua(lua)
+    }
+
+    #[inline]
+    unsafe fn push_into_stack(self, lua: &RawLua) -> Result<()> {
+        self.0.push_into_stack(lua)
+    }
+}
+
+impl FromLua for StrictBool {
+    #[inline]
+    fn from_lua(value: Value, _: &Lua) -> Result<Self> {
+        match value {
+            Value::Boolean(b) => Ok(StrictBool(b)),
+            Value::Nil => Ok(StrictBool(false)),
+            _ => Err(Error::FromLuaConversionError {
+                from: value.type_name(),
+                to: "StrictBool".to_string(),
+                message: Some("expected boolean".to_string()),
+            }),
+        }
+    }
+}
+
+/// An integer wrapper with strict [`FromLua`] conversion.
+///
+/// The plain integer types silently truncate floating point numbers (eg. `3.7` becomes `3`). This
+/// type only accepts Lua numbers that have no fractional part and fit into `T`, so `3.0` is
+/// accepted but `3.5` is rejected. Strings are not coerced to numbers.
+///
+/// # Examples
+///
+/// ```
+/// # use mlua::{Lua, Result, StrictInt};
+/// # fn main() -> Result<()> {
+/// let lua = Lua::new();
+/// assert_eq!(lua.load("3.0").eval::<StrictInt<u8>>()?, StrictInt(3));
+/// assert!(lua.load("3.5").eval::<StrictInt<u8>>().is_err());
+/// assert!(lua.load("256").eval::<StrictInt<u8>>().is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct StrictInt<T>(pub T);
+
+impl<T: IntoLua> IntoLua for StrictInt<T> {
+    #[inline]
+    fn into_lua(self, lua: &Lua) -> Result<Value> {
+        self.0.into_lua(lua)
+    }
+
+    #[inline]
+    unsafe fn push_into_stack(self, lua: &RawLua) -> Result<()> {
+        self.0.push_into_stack(lua)
+    }
+}
+
+impl<T: NumCast> FromLua for StrictInt<T> {
+    fn from_lua(value: Value, _: &Lua) -> Result<Self> {
+        let ty = value.type_name();
+        let error = |message: &str| Error::FromLuaConversionError {
+            from: ty,
+            to: Self::type_name(),
+            message: Some(message.to_string()),
+        };
+        let n = match value {
+            Value::Integer(i) => cast(i),
+            Value::Number(n) if !n.is_finite() => return Err(error("expected finite number")),
+            Value::Number(n) if n.fract() != 0.0 => return Err(error("number has fractional part")),
+            Value::Number(n) => cast(n),
+            _ => return Err(error("expected number")),
+        };
+        n.map(StrictInt).ok_or_else(|| error("out of range"))
+    }
+}
+
+/// A [`String`] wrapper with strict [`FromLua`] conversion.
+///
+/// Unlike [`String`], which also accepts numbers and converts them to strings, this type only
+/// accepts Lua strings. Any other value, including numbers, results in a conversion error. The
+/// string must be valid UTF-8.
+///
+/// # Examples
+///
+/// ```
+/// # use mlua::{Lua, Result, StrictString};
+/// # fn main() -> Result<()> {
+/// let lua = Lua::new();
+/// let f = lua.create_function(|_, StrictString(s): StrictString| Ok(s.len()))?;
+/// assert_eq!(f.call::<usize>("abc")?, 3);
+/// assert!(f.call::<usize>(123).is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct StrictString(pub StdString);
+
+impl IntoLua for StrictString {
+    #[inline]
+    fn into_lua(self, lua: &Lua) -> Result<Value> {
+        self.0.into_lua(lua)
+    }
+
+    #[inline]
+    unsafe fn push_into_stack(self, lua: &RawLua) -> Result<()> {
+        self.0.push_into_stack(lua)
+    }
+}
+
+impl FromLua for StrictString {
+    #[inline]
+    fn from_lua(value: Value, _: &Lua) -> Result<Self> {
+        match value {
+            Value::String(s) => Ok(StrictString(s.to_str()?.to_owned())),
+            _ => Err(Error::FromLuaConversionError {
+                from: value.type_name(),
+                to: "StrictString".to_string(),
+                message: Some("expected string".to_string()),
+            }),
+        }
+    }
+}
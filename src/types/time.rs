@@ -0,0 +1,265 @@
+use std::fmt::Write as _;
+use std::string::String as StdString;
+use std::time::{Duration, Instant};
+
+use crate::error::{Error, Result};
+use crate::multi::MultiValue;
+use crate::state::Lua;
+use crate::traits::{FromLua, IntoLua, IntoLuaMulti};
+use crate::value::Value;
+
+/// A [`Duration`] wrapper that saturates instead of failing when converted from Lua.
+///
+/// The duration is represented in Lua as a number of seconds (fractional part allowed).
+///
+/// When reading a value from Lua, negative numbers are clamped to [`Duration::ZERO`] and numbers
+/// that are too large to be represented are clamped to [`Duration::MAX`]. `NaN` and non-numeric
+/// values are still rejected.
+///
+/// This is useful for lenient configuration values (eg. timers) where an absurd input should be
+/// treated as "never" or "immediately" rather than as an error.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SaturatingDuration(pub Duration);
+
+impl IntoLua for SaturatingDuration {
+    #[inline]
+    fn into_lua(self, _: &Lua) -> Result<Value> {
+        Ok(Value::Number(self.0.as_secs_f64()))
+    }
+}
+
+impl FromLua for SaturatingDuration {
+    fn from_lua(value: Value, lua: &Lua) -> Result<Self> {
+        let ty = value.type_name();
+        let duration = match value {
+            Value::Integer(i) if i < 0 => Duration::ZERO,
+            Value::Integer(i) => Duration::from_secs(i as u64),
+            value => {
+                let secs = lua
+                    .coerce_number(value)?
+                    .ok_or_else(|| Error::FromLuaConversionError {
+                        from: ty,
+                        to: "SaturatingDuration".to_string(),
+                        message: Some("expected number or string coercible to number".to_string()),
+                    })?;
+                if secs.is_nan() {
+                    return Err(Error::FromLuaConversionError {
+                        from: ty,
+                        to: "SaturatingDuration".to_string(),
+                        message: Some("duration cannot be NaN".to_string()),
+                    });
+                }
+                match Duration::try_from_secs_f64(secs) {
+                    Ok(duration) => duration,
+                    Err(_) if secs < 0.0 => Duration::ZERO,
+                    Err(_) => Duration::MAX,
+                }
+            }
+        };
+        Ok(SaturatingDuration(duration))
+    }
+}
+
+/// A [`Duration`] wrapper that is converted to a table with both numeric and human readable forms.
+///
+/// The resulting table has two fields: `seconds` (a number, fractional part allowed) and `pretty`
+/// (a string like `"1h2m3s"`). This is intended for logging and diagnostics, so only [`IntoLua`]
+/// is implemented.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DurationRich(pub Duration);
+
+impl IntoLua for DurationRich {
+    fn into_lua(self, lua: &Lua) -> Result<Value> {
+        let table = lua.create_table_with_capacity(0, 2)?;
+        table.raw_set("seconds", self.0.as_secs_f64())?;
+        table.raw_set("pretty", format_duration(self.0))?;
+        Ok(Value::Table(table))
+    }
+}
+
+/// Formats the duration as hours, minutes and seconds, omitting leading zero components.
+///
+/// Fractional seconds are printed without trailing zeros, eg. `"2m0.5s"`.
+pub(crate) fn format_duration(duration: Duration) -> StdString {
+    let total_secs = duration.as_secs();
+    let (hours, mins, secs) = (total_secs / 3600, total_secs / 60 % 60, total_secs % 60);
+    let nanos = duration.subsec_nanos();
+
+    let mut s = StdString::new();
+    if hours > 0 {
+        let _ = write!(s, "{hours}h");
+    }
+    if hours > 0 || mins > 0 {
+        let _ = write!(s, "{mins}m");
+    }
+    let _ = write!(s, "{secs}");
+    if nanos > 0 {
+        let frac = format!("{nanos:09}");
+        let _ = write!(s, ".{}", frac.trim_end_matches('0'));
+    }
+    s.push('s');
+    s
+}
+
+/// A point in time by which an operation should complete.
+///
+/// In Lua a deadline is represented as the number of seconds remaining until it expires.
+/// [`IntoLua`] emits the remaining time, or `0` if the deadline has already passed. [`FromLua`]
+/// reconstructs a deadline from a non-negative number of seconds counted from now.
+///
+/// This is useful for propagating timeouts into scripts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Deadline(pub Instant);
+
+impl Deadline {
+    /// Creates a deadline that expires after `timeout` from now.
+    pub fn after(timeout: Duration) -> Self {
+        Deadline(Instant::now() + timeout)
+    }
+
+    /// Returns the time remaining until the deadline, or zero if it has passed.
+    pub fn remaining(&self) -> Duration {
+        self.0.saturating_duration_since(Instant::now())
+    }
+}
+
+impl IntoLua for Deadline {
+    #[inline]
+    fn into_lua(self, _: &Lua) -> Result<Value> {
+        Ok(Value::Number(self.remaining().as_secs_f64()))
+    }
+}
+
+impl FromLua for Deadline {
+    fn from_lua(value: Value, lua: &Lua) -> Result<Self> {
+        let ty = value.type_name();
+        let timeout = Duration::from_lua(value, lua)?;
+        match Instant::now().checked_add(timeout) {
+            Some(instant) => Ok(Deadline(instant)),
+            None => Err(Error::FromLuaConversionError {
+                from: ty,
+                to: "Deadline".to_string(),
+                message: Some("deadline is too far in the future".to_string()),
+            }),
+        }
+    }
+}
+
+/// A [`Duration`] wrapper that is converted into two Lua values: whole seconds and nanoseconds.
+///
+/// This follows the common `(secs, nanos)` convention and avoids allocating a table or losing
+/// precision in a floating point number.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SplitDuration(pub Duration);
+
+impl IntoLuaMulti for SplitDuration {
+    fn into_lua_multi(self, lua: &Lua) -> Result<MultiValue> {
+        let secs = i64::try_from(self.0.as_secs()).map_err(|_| Error::ToLuaConversionError {
+            from: "Duration".to_string(),
+            to: "integer",
+            message: Some("seconds out of range".to_string()),
+        })?;
+        (secs, self.0.subsec_nanos()).into_lua_multi(lua)
+    }
+}
+
+/// An event rate, in events per second.
+///
+/// [`IntoLua`] emits the per-second number. [`FromLua`] accepts either a per-second number or a
+/// table `{ interval = <seconds> }` describing the time between events, in which case the rate is
+/// its reciprocal. A zero interval is rejected.
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Rate(pub f64);
+
+impl Rate {
+    /// Creates a rate of one event per `interval`.
+    ///
+    /// Returns `None` if the interval is zero.
+    pub fn from_interval(interval: Duration) -> Option<Self> {
+        if interval.is_zero() {
+            return None;
+        }
+        Some(Rate(1.0 / interval.as_secs_f64()))
+    }
+}
+
+impl IntoLua for Rate {
+    #[inline]
+    fn into_lua(self, _: &Lua) -> Result<Value> {
+        Ok(Value::Number(self.0))
+    }
+}
+
+impl FromLua for Rate {
+    fn from_lua(value: Value, lua: &Lua) -> Result<Self> {
+        let ty = value.type_name();
+        match value {
+            Value::Table(table) => {
+                let interval = table.get::<Duration>("interval")?;
+                Rate::from_interval(interval).ok_or_else(|| Error::FromLuaConversionError {
+                    from: ty,
+                    to: "Rate".to_string(),
+                    message: Some("interval must not be zero".to_string()),
+                })
+            }
+            value => Ok(Rate(f64::from_lua(value, lua)?)),
+        }
+    }
+}
+
+/// A UTC date and time represented in Lua as a Unix timestamp (number of seconds).
+///
+/// Unlike the default [`chrono::DateTime<Utc>`] conversion, which uses RFC 3339 strings,
+/// [`IntoLua`] emits an integer for whole seconds and a float otherwise. Note that a float keeps
+/// only about microsecond precision for present-day timestamps.
+///
+/// [`FromLua`] accepts an integer or a float number of seconds.
+///
+/// [`chrono::DateTime<Utc>`]: chrono::DateTime
+#[cfg(feature = "chrono")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UnixTimestamp(pub chrono::DateTime<chrono::Utc>);
+
+#[cfg(feature = "chrono")]
+impl IntoLua for UnixTimestamp {
+    fn into_lua(self, lua: &Lua) -> Result<Value> {
+        let (secs, nanos) = (self.0.timestamp(), self.0.timestamp_subsec_nanos());
+        if nanos == 0 {
+            return secs.into_lua(lua);
+        }
+        Ok(Value::Number(secs as f64 + nanos as f64 / 1e9))
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl FromLua for UnixTimestamp {
+    fn from_lua(value: Value, lua: &Lua) -> Result<Self> {
+        use chrono::DateTime;
+
+        let ty = value.type_name();
+        let conv_err = |message: StdString| Error::FromLuaConversionError {
+            from: ty,
+            to: "UnixTimestamp".to_string(),
+            message: Some(message),
+        };
+        let dt = match value {
+            #[allow(clippy::useless_conversion)]
+            Value::Integer(i) => DateTime::from_timestamp(i.into(), 0),
+            value => {
+                let secs = f64::from_lua(value, lua)?;
+                if !secs.is_finite() {
+                    return Err(conv_err(format!("timestamp {secs} is not finite")));
+                }
+                let (whole, frac) = (secs.floor(), secs - secs.floor());
+                let nanos = ((frac * 1e9).round() as u32).min(999_999_999);
+                match whole >= i64::MIN as f64 && whole < i64::MAX as f64 {
+                    true => DateTime::from_timestamp(whole as i64, nanos),
+                    false => None,
+                }
+            }
+        };
+        dt.map(UnixTimestamp)
+            .ok_or_else(|| conv_err("timestamp is out of range".to_string()))
+    }
+}
@@ -0,0 +1,82 @@
+use std::fmt;
+use std::num::Wrapping;
+
+use num_traits::{WrappingAdd, WrappingMul, WrappingSub};
+
+use crate::error::Result;
+use crate::state::Lua;
+use crate::traits::{FromLua, IntoLua};
+use crate::types::MaybeSend;
+use crate::userdata::{MetaMethod, UserData, UserDataFields, UserDataMethods};
+use crate::value::Value;
+
+/// A fixed-width integer with wrapping (modular) arithmetic, exposed to Lua as userdata.
+///
+/// The `__add`, `__sub` and `__mul` metamethods wrap around at the boundary of `T` instead of
+/// overflowing, which is useful for hashing or checksum scripts that rely on defined overflow.
+/// Either operand may be a plain Lua integer.
+///
+/// [`FromLua`] accepts either a `WrappingInt<T>` userdata or a plain integer that fits into `T`.
+///
+/// # Examples
+///
+/// ```
+/// # use mlua::{Lua, Result, WrappingInt};
+/// # fn main() -> Result<()> {
+/// let lua = Lua::new();
+/// lua.globals().set("a", WrappingInt::new(250u8))?;
+/// let sum = lua.load("a + 10").eval::<WrappingInt<u8>>()?;
+/// assert_eq!(sum.get(), 4);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct WrappingInt<T>(pub Wrapping<T>);
+
+impl<T> WrappingInt<T> {
+    /// Creates a new `WrappingInt` from the given value.
+    pub const fn new(value: T) -> Self {
+        WrappingInt(Wrapping(value))
+    }
+}
+
+impl<T: Copy> WrappingInt<T> {
+    /// Returns the underlying integer value.
+    pub fn get(&self) -> T {
+        self.0 .0
+    }
+}
+
+impl<T> UserData for WrappingInt<T>
+where
+    T: WrappingAdd + WrappingSub + WrappingMul + Copy + PartialEq + fmt::Display,
+    T: FromLua + IntoLua + MaybeSend + 'static,
+{
+    fn add_fields<F: UserDataFields<Self>>(fields: &mut F) {
+        fields.add_field_method_get("value", |_, this| Ok(this.get()));
+    }
+
+    fn add_methods<M: UserDataMethods<Self>>(methods: &mut M) {
+        methods.add_meta_function(MetaMethod::Add, |_, (a, b): (Self, Self)| {
+            Ok(Self::new(a.get().wrapping_add(&b.get())))
+        });
+        methods.add_meta_function(MetaMethod::Sub, |_, (a, b): (Self, Self)| {
+            Ok(Self::new(a.get().wrapping_sub(&b.get())))
+        });
+        methods.add_meta_function(MetaMethod::Mul, |_, (a, b): (Self, Self)| {
+            Ok(Self::new(a.get().wrapping_mul(&b.get())))
+        });
+        methods.add_meta_function(MetaMethod::Eq, |_, (a, b): (Self, Self)| Ok(a == b));
+        methods.add_meta_method(MetaMethod::ToString, |_, this, ()| Ok(this.get().to_string()));
+    }
+}
+
+impl<T: FromLua + Copy + 'static> FromLua for WrappingInt<T> {
+    #[inline]
+    fn from_lua(value: Value, lua: &Lua) -> Result<Self> {
+        match value {
+            Value::UserData(ud) if ud.is::<Self>() => Ok(*ud.borrow::<Self>()?),
+            value => T::from_lua(value, lua).map(Self::new),
+        }
+    }
+}
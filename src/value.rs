@@ -9,7 +9,7 @@ use num_traits::FromPrimitive;
 use crate::error::{Error, Result};
 use crate::function::Function;
 use crate::string::{BorrowedStr, String};
-use crate::table::Table;
+use crate::table::{Table, TableKind};
 use crate::thread::Thread;
 use crate::types::{Integer, LightUserData, Number, ValueRef};
 use crate::userdata::AnyUserData;
@@ -383,6 +383,46 @@ impl Value {
         }
     }
 
+    /// Classifies the table value as an array, a map, a mix of both or an empty table.
+    ///
+    /// The classification compares the length of the sequence part of the table (as returned by
+    /// [`Table::raw_len`]) with the total number of pairs in the table. Metamethods are not invoked.
+    ///
+    /// Returns an error if the value is not a table.
+    pub fn table_kind(&self) -> Result<TableKind> {
+        let table = self.as_table().ok_or_else(|| Error::FromLuaConversionError {
+            from: self.type_name(),
+            to: "table".to_string(),
+            message: None,
+        })?;
+
+        let seq_len = table.raw_len();
+        let mut count = 0;
+        table.for_each::<Value, Value>(|_, _| {
+            count += 1;
+            Ok(())
+        })?;
+
+        Ok(match (seq_len, count) {
+            (_, 0) => TableKind::Empty,
+            (len, count) if len == count => TableKind::Array,
+            (0, _) => TableKind::Map,
+            _ => TableKind::Mixed,
+        })
+    }
+
+    /// Converts the value into an owned [`PlainValue`] tree that does not hold any Lua handles.
+    ///
+    /// Tables are traversed recursively using raw access (metamethods are not invoked). A table
+    /// whose keys form a sequence `1..=n` becomes [`PlainValue::Array`], any other table becomes
+    /// [`PlainValue::Map`].
+    ///
+    /// Returns an error if the value contains a recursive table or a value that has no plain
+    /// representation (eg. function, thread or userdata).
+    pub fn to_plain(&self) -> Result<PlainValue> {
+        self.to_plain_inner(&mut HashSet::new())
+    }
+
     /// Returns `true` if the value is a Lua [`Thread`].
     #[inline]
     pub fn is_thread(&self) -> bool {
@@ -528,6 +568,50 @@ impl Value {
         }
     }
 
+    fn to_plain_inner(&self, visited: &mut HashSet<*const c_void>) -> Result<PlainValue> {
+        let table = match self {
+            Value::Nil => return Ok(PlainValue::Nil),
+            Value::Boolean(b) => return Ok(PlainValue::Boolean(*b)),
+            Value::Integer(i) => return Ok(PlainValue::Integer(*i)),
+            Value::Number(n) => return Ok(PlainValue::Number(*n)),
+            Value::String(s) => return Ok(PlainValue::String(s.as_bytes().to_vec())),
+            Value::Table(table) => table,
+            _ => {
+                return Err(Error::FromLuaConversionError {
+                    from: self.type_name(),
+                    to: "PlainValue".to_string(),
+                    message: Some("value has no plain representation".to_string()),
+                })
+            }
+        };
+
+        let ptr = table.to_pointer();
+        if !visited.insert(ptr) {
+            return Err(Error::FromLuaConversionError {
+                from: "table",
+                to: "PlainValue".to_string(),
+                message: Some("recursive table detected".to_string()),
+            });
+        }
+        let result = match self.table_kind()? {
+            TableKind::Empty => Ok(PlainValue::Array(Vec::new())),
+            TableKind::Array => (1..=table.raw_len())
+                .map(|i| table.raw_get::<Value>(i)?.to_plain_inner(visited))
+                .collect::<Result<_>>()
+                .map(PlainValue::Array),
+            TableKind::Map | TableKind::Mixed => {
+                let mut pairs = Vec::new();
+                table.for_each::<Value, Value>(|key, value| {
+                    pairs.push((key.to_plain_inner(visited)?, value.to_plain_inner(visited)?));
+                    Ok(())
+                })?;
+                Ok(PlainValue::Map(pairs))
+            }
+        };
+        visited.remove(&ptr);
+        result
+    }
+
     pub(crate) fn fmt_pretty(
         &self,
         fmt: &mut fmt::Formatter,
@@ -626,6 +710,62 @@ impl PartialEq for Value {
     }
 }
 
+/// An owned Lua value that does not reference any Lua state.
+///
+/// Created by [`Value::to_plain`], it can be stored or sent to another thread independently of
+/// the [`Lua`] instance it came from, and converted back using [`IntoLua`]. It is always `Send`
+/// and `Sync`, which makes it suitable for transferring data between independent Lua states.
+///
+/// # Examples
+///
+/// ```
+/// # use mlua::{Lua, PlainValue, Result, Table};
+/// # fn main() -> Result<()> {
+/// let lua1 = Lua::new();
+/// let lua2 = Lua::new();
+///
+/// let data = lua1.load("{ name = 'config', ports = { 80, 443 } }").eval::<PlainValue>()?;
+/// let data = std::thread::spawn(move || data).join().unwrap();
+/// let table = lua2.convert::<Table>(data)?;
+/// assert_eq!(table.get::<Table>("ports")?.get::<i64>(2)?, 443);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`Lua`]: crate::Lua
+/// [`IntoLua`]: crate::IntoLua
+#[derive(Debug, Clone, PartialEq)]
+pub enum PlainValue {
+    /// The Lua value `nil`.
+    Nil,
+    /// The Lua value `true` or `false`.
+    Boolean(bool),
+    /// An integer number.
+    Integer(Integer),
+    /// A floating point number.
+    Number(Number),
+    /// A Lua string (arbitrary bytes).
+    String(Vec<u8>),
+    /// A Lua table with keys forming a sequence `1..=n`.
+    Array(Vec<PlainValue>),
+    /// Any other Lua table, as a list of key-value pairs.
+    Map(Vec<(PlainValue, PlainValue)>),
+}
+
+impl PlainValue {
+    /// Returns the name of the Lua type this value represents.
+    pub const fn type_name(&self) -> &'static str {
+        match *self {
+            PlainValue::Nil => "nil",
+            PlainValue::Boolean(_) => "boolean",
+            PlainValue::Integer(_) => "integer",
+            PlainValue::Number(_) => "number",
+            PlainValue::String(_) => "string",
+            PlainValue::Array(_) | PlainValue::Map(_) => "table",
+        }
+    }
+}
+
 /// A wrapped [`Value`] with customized serialization behavior.
 #[cfg(feature = "serialize")]
 #[cfg_attr(docsrs, doc(cfg(feature = "serialize")))]
@@ -749,4 +889,6 @@ mod assertions {
     static_assertions::assert_not_impl_any!(Value: Send);
     #[cfg(feature = "send")]
     static_assertions::assert_impl_all!(Value: Send, Sync);
+
+    static_assertions::assert_impl_all!(PlainValue: Send, Sync);
 }
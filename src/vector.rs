@@ -86,6 +86,23 @@ impl PartialEq<[f32; Self::SIZE]> for Vector {
     }
 }
 
+/// Allows converting an array into a Luau vector with [`Vector::from`].
+///
+/// Arrays themselves are always converted into Lua tables.
+impl From<[f32; Self::SIZE]> for Vector {
+    #[inline]
+    fn from(value: [f32; Self::SIZE]) -> Self {
+        Vector(value)
+    }
+}
+
+impl From<Vector> for [f32; Vector::SIZE] {
+    #[inline]
+    fn from(value: Vector) -> Self {
+        value.0
+    }
+}
+
 #[cfg(feature = "luau")]
 impl crate::types::LuaType for Vector {
     const TYPE_ID: std::os::raw::c_int = ffi::LUA_TVECTOR;
@@ -0,0 +1,59 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::borrow::Cow;
+use std::cell::Cell;
+
+use mlua::{Function, Lua, Result};
+
+// Counts allocations made by the current thread
+struct CountingAlloc;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|n| n.set(n.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|n| n.set(n.get() + 1));
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+fn count_call_allocations(func: &Function) -> Result<usize> {
+    // Warm up (interns the returned string and initializes internal state)
+    for _ in 0..3 {
+        func.call::<()>(())?;
+    }
+    let before = ALLOCATIONS.with(Cell::get);
+    func.call::<()>(())?;
+    Ok(ALLOCATIONS.with(Cell::get) - before)
+}
+
+// Returning a borrowed `Cow<str>` must not copy it into an owned `String`. After the warm-up calls
+// the string is interned by Lua, so the call allocates no more than an empty callback.
+#[test]
+fn test_cow_str_borrowed_no_owned_copy() -> Result<()> {
+    let lua = Lua::new();
+    lua.gc_stop();
+
+    let baseline = lua.create_function(|_, ()| Ok(()))?;
+    let borrowed = lua.create_function(|_, ()| Ok(Cow::Borrowed("hello")))?;
+    let owned = lua.create_function(|_, ()| Ok(Cow::<str>::Owned("hello".to_string())))?;
+
+    let baseline = count_call_allocations(&baseline)?;
+    assert_eq!(count_call_allocations(&borrowed)?, baseline);
+    assert!(count_call_allocations(&owned)? > baseline);
+
+    Ok(())
+}
@@ -55,3 +55,26 @@ fn test_buffer_out_of_bounds_write() {
     let buf = lua.create_buffer(b"hello, world!").unwrap();
     buf.write_bytes(14, b"!!");
 }
+
+#[test]
+fn test_buffer_into_array() -> Result<()> {
+    let lua = Lua::new();
+
+    let buf = lua.create_buffer([0xde, 0xad, 0xbe, 0xef])?;
+    let header = lua.unpack::<[u8; 4]>(Value::Buffer(buf.clone()))?;
+    assert_eq!(header, [0xde, 0xad, 0xbe, 0xef]);
+
+    let f = lua.create_function(|_, header: [u8; 4]| Ok(u32::from_be_bytes(header)))?;
+    assert_eq!(f.call::<u32>(buf.clone())?, 0xdeadbeef);
+
+    let err = lua.unpack::<[u8; 3]>(Value::Buffer(buf)).unwrap_err();
+    assert!(
+        err.to_string().contains("expected buffer of length 3, got 4"),
+        "{err}"
+    );
+
+    // Tables are still accepted
+    assert_eq!(lua.load("{1, 2, 3, 4}").eval::<[u8; 4]>()?, [1, 2, 3, 4]);
+
+    Ok(())
+}
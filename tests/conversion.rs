@@ -1,13 +1,21 @@
 use std::borrow::Cow;
-use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, LinkedList, VecDeque};
 use std::ffi::{CStr, CString, OsString};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::num::{NonZeroI32, NonZeroI8, NonZeroU32, NonZeroU64, NonZeroU8};
+use std::ops::Bound;
 use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use bstr::BString;
 use maplit::{btreemap, btreeset, hashmap, hashset};
 use mlua::{
-    AnyUserData, BorrowedBytes, BorrowedStr, Either, Error, Function, IntoLua, Lua, RegistryKey, Result,
-    Table, Thread, UserDataRef, Value,
+    AnyUserData, BStr, BasisPoints, BorrowedBytes, BorrowedStr, Color, Deadline, Digest, DurationRich,
+    Either, Error, FiniteFloat, FlagTable, Flags, Fraction01, Function, Hex16, Index, InternedKeys, IntoLua,
+    LatLon, Lazy, Lua, LuaNum, NumberOrString, Quantity, RangeQuery, Rate, Rect, RegistryKey, Result,
+    SaturatingDuration, SeqIter, Snapshot, SparseArray, StrictBool, StrictInt, StrictString, Table,
+    TaggedBlob, Thread, UserDataRef, Value, WrappingInt,
 };
 
 #[test]
@@ -601,6 +609,28 @@ fn test_pathbuf_into_from_lua() -> Result<()> {
     Ok(())
 }
 
+#[cfg(unix)]
+#[test]
+fn test_pathbuf_non_utf8_roundtrip() -> Result<()> {
+    use std::ffi::OsStr;
+    use std::os::unix::ffi::OsStrExt;
+
+    let lua = Lua::new();
+
+    let pb = PathBuf::from(OsStr::from_bytes(b"/tmp/foo\xffbar"));
+    let identity = lua.load("function(p) return p end").eval::<Function>()?;
+    let pb2 = identity.call::<PathBuf>(pb.as_path())?;
+    assert_eq!(pb2, pb);
+
+    let v = lua.pack(pb.clone())?;
+    assert_eq!(v.as_string().unwrap().as_bytes(), b"/tmp/foo\xffbar".as_slice());
+
+    // Numbers are coerced
+    assert_eq!(lua.unpack::<PathBuf>(Value::Integer(123))?, PathBuf::from("123"));
+
+    Ok(())
+}
+
 #[test]
 fn test_option_into_from_lua() -> Result<()> {
     let lua = Lua::new();
@@ -715,6 +745,22 @@ fn test_either_from_lua() -> Result<()> {
         err => panic!("expected `Error::CallbackError`, got {err:?}"),
     }
 
+    // Both attempted conversions are reported
+    let err = lua
+        .unpack::<Either<i32, Table>>(Value::Boolean(true))
+        .unwrap_err();
+    let msg = err.to_string();
+    assert!(
+        msg.contains("as i32: error converting Lua boolean to i32"),
+        "{msg}"
+    );
+    assert!(
+        msg.contains("as Table: error converting Lua boolean to table"),
+        "{msg}"
+    );
+    let err = f.call::<Value>("hello").unwrap_err().to_string();
+    assert!(err.contains("as i32:") && err.contains("as Table:"), "{err}");
+
     Ok(())
 }
 
@@ -742,9 +788,1829 @@ fn test_char_from_lua() -> Result<()> {
     assert!(lua
         .convert::<char>("hello")
         .is_err_and(|e| e.to_string().contains("expected string to have exactly one char")));
+    assert!(lua
+        .convert::<char>("ab")
+        .is_err_and(|e| e.to_string().contains("got more than one character")));
+    assert!(lua
+        .convert::<char>("")
+        .is_err_and(|e| e.to_string().contains("got empty string")));
+
+    // Multibyte characters
+    assert_eq!(lua.convert::<char>("é")?, 'é');
+    assert_eq!(lua.convert::<char>("😀")?, '😀');
+    assert_eq!(lua.convert::<char>('😀')?, '😀');
+    assert_eq!(lua.convert::<String>('é')?, "é");
+    assert!(lua.convert::<char>("é!").is_err());
     assert!(lua
         .convert::<char>(HashMap::<String, String>::new())
         .is_err_and(|e| e.to_string().contains("expected string or integer")));
 
     Ok(())
 }
+
+#[test]
+fn test_saturating_duration() -> Result<()> {
+    let lua = Lua::new();
+
+    let d = lua.convert::<SaturatingDuration>(1.5)?;
+    assert_eq!(d.0, Duration::from_millis(1500));
+    let d = lua.convert::<SaturatingDuration>(3)?;
+    assert_eq!(d.0, Duration::from_secs(3));
+
+    // Overflow clamps to MAX
+    let d = lua.convert::<SaturatingDuration>(1e300)?;
+    assert_eq!(d.0, Duration::MAX);
+    let d = lua.load("math.huge").eval::<SaturatingDuration>()?;
+    assert_eq!(d.0, Duration::MAX);
+
+    // Negative values clamp to ZERO
+    let d = lua.convert::<SaturatingDuration>(-1.5)?;
+    assert_eq!(d.0, Duration::ZERO);
+    let d = lua.convert::<SaturatingDuration>(-10)?;
+    assert_eq!(d.0, Duration::ZERO);
+
+    // NaN and non-numbers are still errors
+    assert!(lua.load("0/0").eval::<SaturatingDuration>().is_err());
+    assert!(lua.convert::<SaturatingDuration>("abc").is_err());
+
+    // Into Lua
+    let v = SaturatingDuration(Duration::from_millis(250)).into_lua(&lua)?;
+    assert_eq!(v, Value::Number(0.25));
+
+    Ok(())
+}
+
+#[test]
+fn test_flag_table() -> Result<()> {
+    #[derive(Debug, PartialEq)]
+    struct Permissions(u32);
+
+    impl FlagTable for Permissions {
+        fn flag_bit(name: &str) -> Option<u32> {
+            match name {
+                "read" => Some(0),
+                "write" => Some(1),
+                "exec" => Some(2),
+                _ => None,
+            }
+        }
+
+        fn from_bits(bits: u64) -> Self {
+            Permissions(bits as u32)
+        }
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct StrictPermissions(u32);
+
+    impl FlagTable for StrictPermissions {
+        const DENY_UNKNOWN_FLAGS: bool = true;
+
+        fn flag_bit(name: &str) -> Option<u32> {
+            Permissions::flag_bit(name)
+        }
+
+        fn from_bits(bits: u64) -> Self {
+            StrictPermissions(bits as u32)
+        }
+    }
+
+    let lua = Lua::new();
+
+    let Flags(perms) = lua
+        .load("{ read = true, write = false, exec = true }")
+        .eval::<Flags<Permissions>>()?;
+    assert_eq!(perms, Permissions(0b101));
+
+    // Unknown keys are ignored by default
+    let Flags(perms) = lua
+        .load("{ write = true, delete = true, [1] = true }")
+        .eval::<Flags<Permissions>>()?;
+    assert_eq!(perms, Permissions(0b010));
+
+    // ...or rejected if configured
+    let res = lua
+        .load("{ write = true, delete = true }")
+        .eval::<Flags<StrictPermissions>>();
+    assert!(res.is_err_and(|err| err.to_string().contains("unknown flag delete")));
+    let Flags(perms) = lua.load("{ read = true }").eval::<Flags<StrictPermissions>>()?;
+    assert_eq!(perms, StrictPermissions(0b001));
+
+    // Non-boolean values are rejected
+    let res = lua.load("{ read = 1 }").eval::<Flags<Permissions>>();
+    assert!(res.is_err_and(|err| err.to_string().contains("expected boolean for flag read")));
+
+    // Non-table values are rejected
+    assert!(lua.convert::<Flags<Permissions>>("read").is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_hex16() -> Result<()> {
+    let lua = Lua::new();
+
+    let bytes = [
+        0x67, 0xe5, 0x50, 0x44, 0x10, 0xb1, 0x42, 0x6f, 0x92, 0x47, 0xbb, 0x68, 0x0e, 0x5f, 0xe0, 0xc8,
+    ];
+
+    let v = Hex16(bytes).into_lua(&lua)?;
+    assert_eq!(v.as_str().unwrap(), "67e5504410b1426f9247bb680e5fe0c8");
+
+    // Non-hyphenated form (any case)
+    assert_eq!(lua.unpack::<Hex16>(v)?, Hex16(bytes));
+    let id = lua.convert::<Hex16>("67E5504410B1426F9247BB680E5FE0C8")?;
+    assert_eq!(id, Hex16(bytes));
+
+    // Hyphenated form
+    let id = lua.convert::<Hex16>("67e55044-10b1-426f-9247-bb680e5fe0c8")?;
+    assert_eq!(id, Hex16(bytes));
+
+    // Wrong length
+    let res = lua.convert::<Hex16>("67e55044-10b1-426f");
+    assert!(res.is_err_and(|err| err.to_string().contains("expected 32 hex digits, got 16")));
+
+    // Non-hex characters
+    let res = lua.convert::<Hex16>("zze5504410b1426f9247bb680e5fe0c8");
+    assert!(res.is_err_and(|err| err.to_string().contains("invalid hex character 'z'")));
+
+    // Non-string values
+    assert!(lua.convert::<Hex16>(123).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_wrapping_int() -> Result<()> {
+    let lua = Lua::new();
+
+    lua.globals().set("a", WrappingInt::new(250u8))?;
+    lua.globals().set("b", WrappingInt::new(10u8))?;
+
+    // Wraps at the type boundary
+    let sum = lua.load("a + b").eval::<WrappingInt<u8>>()?;
+    assert_eq!(sum.get(), 4);
+    let diff = lua.load("b - a").eval::<WrappingInt<u8>>()?;
+    assert_eq!(diff.get(), 16);
+    let prod = lua.load("a * b").eval::<WrappingInt<u8>>()?;
+    assert_eq!(prod.get(), 250u8.wrapping_mul(10));
+
+    // Plain integers are accepted on either side
+    let sum = lua.load("a + 6").eval::<WrappingInt<u8>>()?;
+    assert_eq!(sum.get(), 0);
+    let sum = lua.load("6 + a").eval::<WrappingInt<u8>>()?;
+    assert_eq!(sum.get(), 0);
+    assert_eq!(lua.convert::<WrappingInt<u8>>(7)?.get(), 7);
+
+    // Wider types
+    lua.globals().set("c", WrappingInt::new(u32::MAX))?;
+    let sum = lua.load("c + 1").eval::<WrappingInt<u32>>()?;
+    assert_eq!(sum.get(), 0);
+
+    lua.load(
+        r#"
+        assert(tostring(a + b) == "4")
+        assert((a + b).value == 4)
+        assert(a + 6 == b - 10)
+    "#,
+    )
+    .exec()?;
+
+    Ok(())
+}
+
+#[test]
+fn test_basis_points() -> Result<()> {
+    let lua = Lua::new();
+
+    assert_eq!(lua.convert::<BasisPoints>(0)?, BasisPoints(0));
+    assert_eq!(lua.convert::<BasisPoints>(2550)?, BasisPoints(2550));
+    assert_eq!(lua.convert::<BasisPoints>(10000)?, BasisPoints::MAX);
+
+    // Out of range
+    let res = lua.convert::<BasisPoints>(10001);
+    assert!(res.is_err_and(|err| err.to_string().contains("value 10001 is out of range 0..=10000")));
+    assert!(lua.convert::<BasisPoints>(-1).is_err());
+    assert!(lua.convert::<BasisPoints>(i64::MAX).is_err());
+
+    // Into Lua as an integer
+    let f = lua.create_function(|_, bp: BasisPoints| Ok(BasisPoints(bp.0 / 2)))?;
+    assert_eq!(f.call::<Value>(5000)?, Value::Integer(2500));
+
+    Ok(())
+}
+
+#[test]
+fn test_duration_rich() -> Result<()> {
+    let lua = Lua::new();
+
+    let d = DurationRich(Duration::from_secs(3723));
+    let t = lua.convert::<Table>(d)?;
+    assert_eq!(t.get::<f64>("seconds")?, 3723.0);
+    assert_eq!(t.get::<String>("pretty")?, "1h2m3s");
+
+    let d = DurationRich(Duration::from_millis(120_500));
+    let t = lua.convert::<Table>(d)?;
+    assert_eq!(t.get::<f64>("seconds")?, 120.5);
+    assert_eq!(t.get::<String>("pretty")?, "2m0.5s");
+
+    let t = lua.convert::<Table>(DurationRich(Duration::ZERO))?;
+    assert_eq!(t.get::<String>("pretty")?, "0s");
+
+    Ok(())
+}
+
+#[cfg(feature = "smallvec")]
+#[test]
+fn test_smallvec() -> Result<()> {
+    use smallvec::SmallVec;
+
+    let lua = Lua::new();
+
+    // Fits into the inline buffer
+    let v = lua.load("{1, 2, 3}").eval::<SmallVec<[i32; 4]>>()?;
+    assert_eq!(v.as_slice(), &[1, 2, 3]);
+    assert!(!v.spilled());
+
+    // Spills to the heap
+    let v = lua.load("{1, 2, 3, 4, 5, 6}").eval::<SmallVec<[i32; 4]>>()?;
+    assert_eq!(v.as_slice(), &[1, 2, 3, 4, 5, 6]);
+    assert!(v.spilled());
+
+    let t = lua.convert::<Table>(SmallVec::<[i32; 2]>::from_slice(&[7, 8, 9]))?;
+    assert_eq!(
+        t.sequence_values::<i32>().collect::<Result<Vec<_>>>()?,
+        vec![7, 8, 9]
+    );
+
+    // Not a table
+    assert!(lua.convert::<SmallVec<[i32; 4]>>(123).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_duration_sub() -> Result<()> {
+    let lua = Lua::new();
+
+    let duration_sub = lua.create_duration_sub()?;
+    assert_eq!(duration_sub.call::<f64>((5, 1.5))?, 3.5);
+    assert_eq!(duration_sub.call::<f64>((2, 2))?, 0.0);
+
+    // Underflow returns nil and an error message
+    let (d, err) = duration_sub.call::<(Option<f64>, String)>((1, 2))?;
+    assert_eq!(d, None);
+    assert_eq!(err, "duration subtraction underflow");
+
+    // Negative durations are rejected
+    assert!(duration_sub.call::<f64>((-1, 2)).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_snapshot() -> Result<()> {
+    let lua = Lua::new();
+
+    let mut data = Arc::new(vec![1, 2, 3]);
+    let t = lua.convert::<Table>(Snapshot(data.clone()))?;
+
+    // Mutating the original does not affect the Lua copy
+    Arc::make_mut(&mut data).push(4);
+    assert_eq!(data.len(), 4);
+    assert_eq!(t.raw_len(), 3);
+    assert_eq!(
+        t.sequence_values::<i32>().collect::<Result<Vec<_>>>()?,
+        vec![1, 2, 3]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_index() -> Result<()> {
+    let lua = Lua::new();
+
+    assert_eq!(lua.convert::<Index>(1)?, Index(0));
+    assert_eq!(lua.convert::<Index>(1_000_000_000)?, Index(999_999_999));
+    assert_eq!(lua.convert::<Index>(2.0)?, Index(1));
+
+    let res = lua.convert::<Index>(0);
+    assert!(res.is_err_and(|err| err.to_string().contains("Lua indices are 1-based")));
+    let res = lua.convert::<Index>(-5);
+    assert!(res.is_err_and(|err| err.to_string().contains("Lua indices are 1-based")));
+
+    // Converts back to 1-based
+    assert_eq!(Index(0).into_lua(&lua)?, Value::Integer(1));
+
+    Ok(())
+}
+
+#[test]
+fn test_deadline() -> Result<()> {
+    let lua = Lua::new();
+
+    // Future deadline
+    let remaining = lua.convert::<f64>(Deadline::after(Duration::from_secs(60)))?;
+    assert!(remaining > 59.0 && remaining <= 60.0);
+
+    // Passed deadline is clamped to zero
+    let passed = Deadline(Instant::now() - Duration::from_millis(10));
+    assert_eq!(passed.into_lua(&lua)?, Value::Number(0.0));
+
+    // Reconstruct from a number of seconds
+    let deadline = lua.convert::<Deadline>(1.5)?;
+    let remaining = deadline.remaining();
+    assert!(remaining > Duration::from_secs(1) && remaining <= Duration::from_millis(1500));
+    assert!(lua.convert::<Deadline>(-1).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_lazy() -> Result<()> {
+    let lua = Lua::new();
+
+    let calls = std::cell::Cell::new(0);
+    let value = Lazy(|lua: &Lua| {
+        calls.set(calls.get() + 1);
+        lua.create_sequence_from([1, 2, 3])?.into_lua(lua)
+    });
+    assert_eq!(calls.get(), 0);
+
+    lua.globals().set("t", value)?;
+    assert_eq!(calls.get(), 1);
+    assert_eq!(lua.load("#t").eval::<i64>()?, 3);
+    assert_eq!(calls.get(), 1);
+
+    Ok(())
+}
+
+#[test]
+fn test_interned_keys() -> Result<()> {
+    let lua = Lua::new();
+
+    let InternedKeys(map1) = lua.load("{ alpha = 1, beta = 2 }").eval::<InternedKeys<i32>>()?;
+    let InternedKeys(map2) = lua.load("{ alpha = 3 }").eval::<InternedKeys<i32>>()?;
+    assert_eq!(map1["alpha"], 1);
+    assert_eq!(map1["beta"], 2);
+    assert_eq!(map2["alpha"], 3);
+
+    // The same key shares one allocation
+    let key1 = map1.keys().find(|k| &***k == "alpha").unwrap();
+    let key2 = map2.keys().next().unwrap();
+    assert!(Arc::ptr_eq(key1, key2));
+
+    let interner = lua.string_interner();
+    assert_eq!(interner.len(), 2);
+    assert!(Arc::ptr_eq(
+        &interner.intern("beta"),
+        map1.keys().find(|k| &***k == "beta").unwrap()
+    ));
+
+    // Keys must be strings
+    assert!(lua.load("{ [true] = 1 }").eval::<InternedKeys<i32>>().is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_color() -> Result<()> {
+    let lua = Lua::new();
+
+    let c = lua.load("{ r = 255, g = 128, b = 0, a = 64 }").eval::<Color>()?;
+    assert_eq!(c, Color::rgba(255, 128, 0, 64));
+    let c = lua.load("{ r = 1, g = 2, b = 3 }").eval::<Color>()?;
+    assert_eq!(c, Color::rgb(1, 2, 3));
+
+    let c = lua.load("{ 10, 20, 30, 40 }").eval::<Color>()?;
+    assert_eq!(c, Color::rgba(10, 20, 30, 40));
+    let c = lua.load("{ 10, 20, 30 }").eval::<Color>()?;
+    assert_eq!(c, Color::rgb(10, 20, 30));
+
+    assert_eq!(lua.convert::<Color>("#FF8000")?, Color::rgb(255, 128, 0));
+    assert_eq!(lua.convert::<Color>("#ff800040")?, Color::rgba(255, 128, 0, 64));
+
+    // Into Lua
+    let t = lua.convert::<Table>(Color::rgba(1, 2, 3, 4))?;
+    assert_eq!(
+        (
+            t.get::<u8>("r")?,
+            t.get::<u8>("g")?,
+            t.get::<u8>("b")?,
+            t.get::<u8>("a")?
+        ),
+        (1, 2, 3, 4)
+    );
+
+    // Errors
+    let res = lua.convert::<Color>("#FF80");
+    assert!(res.is_err_and(|err| err.to_string().contains("expected 6 or 8 hex digits, got 4")));
+    let res = lua.convert::<Color>("#GG8000");
+    assert!(res.is_err_and(|err| err.to_string().contains("invalid hex character 'G'")));
+    assert!(lua.convert::<Color>("FF8000").is_err());
+    assert!(lua.load("{ r = 256, g = 0, b = 0 }").eval::<Color>().is_err());
+    assert!(lua.load("{ 1, 2 }").eval::<Color>().is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_fraction01() -> Result<()> {
+    let lua = Lua::new();
+
+    assert_eq!(lua.convert::<Fraction01>(0.5)?, Fraction01(0.5));
+    assert_eq!(lua.convert::<Fraction01>(1.0)?, Fraction01(1.0));
+    assert_eq!(lua.convert::<Fraction01>(0)?, Fraction01(0.0));
+
+    let res = lua.convert::<Fraction01>(1.5);
+    assert!(res.is_err_and(|err| err.to_string().contains("value 1.5 is out of range 0.0..=1.0")));
+    assert!(lua.convert::<Fraction01>(-0.1).is_err());
+    assert!(lua.convert::<Fraction01>(f64::NAN).is_err());
+
+    assert_eq!(Fraction01(0.25).into_lua(&lua)?, Value::Number(0.25));
+
+    Ok(())
+}
+
+#[test]
+fn test_monotonic_clock() -> Result<()> {
+    let lua = Lua::new();
+
+    let t1 = lua.monotonic_now();
+    let t2 = lua.monotonic_now();
+    assert!(t2 >= t1);
+
+    let monotonic_now = lua.create_monotonic_clock()?;
+    let s1 = monotonic_now.call::<f64>(())?;
+    std::thread::sleep(Duration::from_millis(5));
+    let s2 = monotonic_now.call::<f64>(())?;
+    assert!(s2 >= s1 + 0.005);
+    assert!(s1 >= t2.as_secs_f64());
+    assert!(Duration::from_secs_f64(s2) <= lua.monotonic_now());
+
+    Ok(())
+}
+
+#[test]
+fn test_lat_lon() -> Result<()> {
+    let lua = Lua::new();
+
+    let london = LatLon {
+        lat: 51.5,
+        lon: -0.125,
+    };
+    assert_eq!(lua.load("{ lat = 51.5, lon = -0.125 }").eval::<LatLon>()?, london);
+    assert_eq!(lua.load("{ 51.5, -0.125 }").eval::<LatLon>()?, london);
+    assert_eq!(lua.convert::<LatLon>("51.5, -0.125")?, london);
+    assert_eq!(
+        lua.convert::<LatLon>("-90,180")?,
+        LatLon {
+            lat: -90.0,
+            lon: 180.0
+        }
+    );
+
+    let t = lua.convert::<Table>(london)?;
+    assert_eq!(t.get::<f64>("lat")?, 51.5);
+    assert_eq!(t.get::<f64>("lon")?, -0.125);
+
+    // Errors
+    let res = lua.load("{ lat = 91, lon = 0 }").eval::<LatLon>();
+    assert!(res.is_err_and(|err| err.to_string().contains("latitude 91 is out of range")));
+    let res = lua.convert::<LatLon>("0,-181");
+    assert!(res.is_err_and(|err| err.to_string().contains("longitude -181 is out of range")));
+    assert!(lua.convert::<LatLon>("51.5").is_err());
+    assert!(lua.convert::<LatLon>("a,b").is_err());
+    assert!(lua.load("{ 1, 2, 3 }").eval::<LatLon>().is_err());
+
+    let err = LatLon::new(0.0, f64::NAN).unwrap_err();
+    assert!(matches!(err, Error::RuntimeError(ref msg) if msg.contains("longitude NaN is out of range")));
+    assert!(matches!(
+        lua.convert::<LatLon>("91,0"),
+        Err(Error::FromLuaConversionError { from: "string", .. })
+    ));
+
+    Ok(())
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn test_par_vec() -> Result<()> {
+    use mlua::ParVec;
+
+    let lua = Lua::new();
+
+    let ParVec(v) = lua
+        .load("local t = {} for i = 1, 1000000 do t[i] = i end return t")
+        .eval::<ParVec<i64>>()?;
+    assert_eq!(v.len(), 1_000_000);
+    assert!(v.iter().enumerate().all(|(i, &x)| x == i as i64 + 1));
+
+    // Non-numeric values are rejected
+    let res = lua.load("{1.5, 2, true}").eval::<ParVec<f64>>();
+    assert!(res.is_err_and(|err| err.to_string().contains("expected number")));
+
+    let ParVec(v) = lua.load("{1.5, 2}").eval::<ParVec<f32>>()?;
+    assert_eq!(v, vec![1.5, 2.0]);
+    let ParVec(v) = lua.load("{true, false}").eval::<ParVec<bool>>()?;
+    assert_eq!(v, vec![true, false]);
+
+    // Out of range
+    assert!(lua.load("{1, 256}").eval::<ParVec<u8>>().is_err());
+    // Not a table
+    assert!(lua.convert::<ParVec<i64>>(1).is_err());
+
+    // Back to Lua
+    let t = lua.convert::<Table>(ParVec(vec![1, 2, 3]))?;
+    assert_eq!(t.raw_len(), 3);
+
+    Ok(())
+}
+
+#[test]
+fn test_tagged_blob() -> Result<()> {
+    let lua = Lua::new();
+
+    let blob = TaggedBlob {
+        version: 3,
+        data: vec![0, 1, 2, 255],
+    };
+    lua.globals().set("blob", blob.clone())?;
+    lua.load(r#"assert(blob.version == 3 and blob.data == "\0\1\2\255")"#)
+        .exec()?;
+    assert_eq!(lua.globals().get::<TaggedBlob>("blob")?, blob);
+
+    // Non-string data is rejected
+    let res = lua.load("{ version = 1, data = 123 }").eval::<TaggedBlob>();
+    assert!(res.is_err_and(|err| err.to_string().contains("expected string for `data` field")));
+    let res = lua.load("{ version = 1 }").eval::<TaggedBlob>();
+    assert!(res.is_err());
+    assert!(lua
+        .load("{ version = 70000, data = '' }")
+        .eval::<TaggedBlob>()
+        .is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_range_query() -> Result<()> {
+    let lua = Lua::new();
+    let map = (1..=10).map(|i| (i, i * 10)).collect::<BTreeMap<i32, i32>>();
+    let query = |code: &str| -> Result<Vec<i32>> {
+        let query = lua.load(code).eval::<RangeQuery<i32>>()?;
+        Ok(map.range(query).map(|(k, _)| *k).collect())
+    };
+
+    // Default inclusivity matches `lo..hi`
+    assert_eq!(query("{ lo = 2, hi = 5 }")?, vec![2, 3, 4]);
+    assert_eq!(
+        query("{ lo = 2, hi = 5, hi_inclusive = true }")?,
+        vec![2, 3, 4, 5]
+    );
+    assert_eq!(query("{ lo = 2, hi = 5, lo_inclusive = false }")?, vec![3, 4]);
+    assert_eq!(query("{ lo = 5, hi = 5, hi_inclusive = true }")?, vec![5]);
+
+    // Unbounded
+    assert_eq!(query("{ lo = 8 }")?, vec![8, 9, 10]);
+    assert_eq!(query("{ hi = 3 }")?, vec![1, 2]);
+    assert_eq!(query("{}")?.len(), 10);
+    let q = lua.load("{}").eval::<RangeQuery<i32>>()?;
+    assert_eq!((q.lo, q.hi), (Bound::Unbounded, Bound::Unbounded));
+
+    // Inverted range
+    let res = lua.load("{ lo = 5, hi = 2 }").eval::<RangeQuery<i32>>();
+    assert!(res.is_err_and(|err| err
+        .to_string()
+        .contains("lower bound is greater than upper bound")));
+    let res = lua
+        .load("{ lo = 5, hi = 5, lo_inclusive = false }")
+        .eval::<RangeQuery<i32>>();
+    assert!(res.is_err_and(|err| err.to_string().contains("bounds are equal and both exclusive")));
+
+    // Inclusivity flags must be booleans
+    assert!(query("{ lo = 2, hi = 5, lo_inclusive = 'no' }").is_err());
+    assert!(query("{ lo = 2, hi = 5, hi_inclusive = 0 }").is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_duration() -> Result<()> {
+    let lua = Lua::new();
+
+    // Round trip through a Lua function
+    let f = lua.load("function(d) return d end").eval::<Function>()?;
+    let d = Duration::from_millis(1500);
+    assert_eq!(f.call::<Duration>(d)?, d);
+    assert_eq!(d.into_lua(&lua)?, Value::Number(1.5));
+
+    assert_eq!(lua.convert::<Duration>(3)?, Duration::from_secs(3));
+    assert_eq!(lua.convert::<Duration>(0.25)?, Duration::from_millis(250));
+    // Sub-nanosecond values are rounded to the nearest nanosecond
+    assert_eq!(lua.convert::<Duration>(1.0000000006)?, Duration::new(1, 1));
+    assert_eq!(lua.convert::<Duration>(1.0000000004)?, Duration::new(1, 0));
+
+    // Negative values are rejected
+    let res = lua.convert::<Duration>(-1);
+    assert!(res.is_err_and(|err| err.to_string().contains("duration cannot be negative")));
+    let res = lua.convert::<Duration>(-0.5);
+    assert!(res.is_err_and(|err| err.to_string().contains("duration cannot be negative")));
+    assert!(lua.convert::<Duration>(f64::NAN).is_err());
+    assert!(lua.convert::<Duration>(true).is_err());
+
+    Ok(())
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn test_chrono() -> Result<()> {
+    use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+
+    let lua = Lua::new();
+
+    // RFC 3339 round trip
+    let dt = DateTime::parse_from_rfc3339("2024-01-02T03:04:05.250+02:00")
+        .unwrap()
+        .with_timezone(&Utc);
+    let s = lua.convert::<String>(dt)?;
+    assert_eq!(s, "2024-01-02T01:04:05.250Z");
+    assert_eq!(lua.convert::<DateTime<Utc>>(s)?, dt);
+
+    // Unix timestamp
+    let dt = lua.convert::<DateTime<Utc>>(1_700_000_000)?;
+    assert_eq!(dt.to_rfc3339(), "2023-11-14T22:13:20+00:00");
+
+    // Naive date and time
+    let naive = NaiveDate::from_ymd_opt(2024, 1, 2)
+        .unwrap()
+        .and_hms_milli_opt(3, 4, 5, 6)
+        .unwrap();
+    let s = lua.convert::<String>(naive)?;
+    assert_eq!(s, "2024-01-02T03:04:05.006");
+    assert_eq!(lua.convert::<NaiveDateTime>(s)?, naive);
+
+    // Malformed strings
+    let res = lua.convert::<DateTime<Utc>>("2024-13-01T00:00:00Z");
+    assert!(res.is_err_and(|err| err.to_string().contains("invalid RFC 3339 date and time")));
+    let res = lua.convert::<NaiveDateTime>("yesterday");
+    assert!(res.is_err_and(|err| err.to_string().contains("invalid date and time")));
+    assert!(lua.convert::<DateTime<Utc>>(true).is_err());
+
+    // Unix timestamp wrapper
+    let dt = DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+    let v = lua.pack(mlua::UnixTimestamp(dt))?;
+    assert_eq!(v, Value::Integer(1_700_000_000));
+    assert_eq!(lua.unpack::<mlua::UnixTimestamp>(v)?.0, dt);
+    let dt = DateTime::from_timestamp(1_700_000_000, 250_000_000).unwrap();
+    let v = lua.pack(mlua::UnixTimestamp(dt))?;
+    assert_eq!(v, Value::Number(1_700_000_000.25));
+    assert_eq!(lua.unpack::<mlua::UnixTimestamp>(v)?.0, dt);
+    assert!(lua.convert::<mlua::UnixTimestamp>(f64::INFINITY).is_err());
+    assert!(lua.convert::<mlua::UnixTimestamp>(1e300).is_err());
+
+    Ok(())
+}
+
+// `Integer` is 32-bit in Luau, so timestamps are widened before being passed to chrono
+#[cfg(all(feature = "luau", feature = "chrono"))]
+#[test]
+fn test_chrono_luau() -> Result<()> {
+    use chrono::{DateTime, Utc};
+
+    let lua = Lua::new();
+
+    let dt = lua.unpack::<DateTime<Utc>>(Value::Integer(1_700_000_000))?;
+    assert_eq!(dt.to_rfc3339(), "2023-11-14T22:13:20+00:00");
+    let dt = lua.unpack::<DateTime<Utc>>(Value::Integer(mlua::Integer::MIN))?;
+    assert_eq!(dt.timestamp(), i64::from(mlua::Integer::MIN));
+
+    Ok(())
+}
+
+#[test]
+fn test_seq_iter() -> Result<()> {
+    let lua = Lua::new();
+
+    lua.globals().set("xs", SeqIter(0..100))?;
+    lua.load("assert(#xs == 100 and xs[1] == 0 and xs[100] == 99)")
+        .exec()?;
+
+    let v = lua.pack(SeqIter(["a", "b"].iter().map(|s| s.to_uppercase())))?;
+    assert_eq!(v.as_table().unwrap(), &["A", "B"]);
+
+    // Empty iterator produces an empty table
+    let v = lua.pack(SeqIter(std::iter::empty::<i32>()))?;
+    assert!(v.is_table());
+    assert_eq!(v.as_table().unwrap().raw_len(), 0);
+
+    Ok(())
+}
+
+#[test]
+fn test_timeout_guard() -> Result<()> {
+    let lua = Lua::new();
+
+    let timed_out = lua.create_timeout_guard(Duration::from_millis(1500))?;
+    assert!(!timed_out.call::<bool>(1)?);
+    assert!(!timed_out.call::<bool>(1.5)?);
+    assert!(timed_out.call::<bool>(1.501)?);
+    assert!(timed_out.call::<bool>(10)?);
+    assert!(timed_out.call::<bool>(-1).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_strict_bool() -> Result<()> {
+    let lua = Lua::new();
+
+    let f = lua.create_function(|_, StrictBool(b): StrictBool| Ok(b))?;
+    assert!(f.call::<bool>(true)?);
+    assert!(!f.call::<bool>(false)?);
+    assert!(!f.call::<bool>(Value::Nil)?);
+
+    // Truthy values are rejected rather than treated as `true`
+    let err = f.call::<bool>("true").unwrap_err().to_string();
+    assert!(err.contains("expected boolean"), "{err}");
+    assert!(f.call::<bool>(1).is_err());
+
+    assert_eq!(lua.pack(StrictBool(true))?.as_boolean(), Some(true));
+
+    Ok(())
+}
+
+#[test]
+fn test_strict_string() -> Result<()> {
+    let lua = Lua::new();
+
+    let f = lua.create_function(|_, StrictString(s): StrictString| Ok(s))?;
+    assert_eq!(f.call::<String>("key")?, "key");
+
+    // Numbers are rejected rather than coerced
+    let err = f.call::<String>(42).unwrap_err().to_string();
+    assert!(err.contains("expected string"), "{err}");
+    assert!(f.call::<String>(1.5).is_err());
+    assert!(f.call::<String>(Value::Nil).is_err());
+    assert!(lua.unpack::<StrictString>(Value::Integer(42)).is_err());
+    // The lenient conversion still coerces
+    assert_eq!(lua.unpack::<String>(Value::Integer(42))?, "42");
+
+    assert_eq!(lua.pack(StrictString("s".into()))?.as_str().unwrap(), "s");
+
+    Ok(())
+}
+
+#[test]
+fn test_lua_num() -> Result<()> {
+    let lua = Lua::new();
+
+    assert_eq!(lua.load("3").eval::<LuaNum>()?, LuaNum::Int(3));
+    #[cfg(any(feature = "lua54", feature = "lua53"))]
+    assert_eq!(lua.load("3.0").eval::<LuaNum>()?, LuaNum::Float(3.0));
+    assert_eq!(lua.load("3.5").eval::<LuaNum>()?, LuaNum::Float(3.5));
+    assert!(lua.load("'3'").eval::<LuaNum>().is_err());
+
+    // Mixing integer and float promotes to float
+    assert_eq!(LuaNum::Int(2) + LuaNum::Int(3), LuaNum::Int(5));
+    assert_eq!(LuaNum::Int(2) + LuaNum::Float(0.5), LuaNum::Float(2.5));
+    assert_eq!(LuaNum::Float(1.5) * LuaNum::Int(2), LuaNum::Float(3.0));
+    assert_eq!(LuaNum::Int(1) / LuaNum::Int(2), LuaNum::Float(0.5));
+
+    // Subtype is preserved when converting back to Lua
+    #[cfg(any(feature = "lua54", feature = "lua53"))]
+    {
+        let is_int = lua
+            .load("function(x) return math.type(x) == 'integer' end")
+            .eval::<Function>()?;
+        assert!(is_int.call::<bool>(LuaNum::Int(3))?);
+        assert!(!is_int.call::<bool>(LuaNum::Float(3.0))?);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_number_or_string() -> Result<()> {
+    let lua = Lua::new();
+
+    assert_eq!(
+        lua.load("5").eval::<NumberOrString>()?,
+        NumberOrString::Number(5.0)
+    );
+    assert_eq!(
+        lua.load("2.5").eval::<NumberOrString>()?,
+        NumberOrString::Number(2.5)
+    );
+    assert_eq!(
+        lua.load("'5'").eval::<NumberOrString>()?,
+        NumberOrString::String("5".into())
+    );
+    assert_eq!(
+        lua.load("'abc'").eval::<NumberOrString>()?,
+        NumberOrString::String("abc".into())
+    );
+    let err = lua.load("true").eval::<NumberOrString>().unwrap_err();
+    assert!(err.to_string().contains("expected number or string"), "{err}");
+    assert!(lua.load("nil").eval::<NumberOrString>().is_err());
+
+    // Representation is kept when converting back
+    let f = lua.load("function(x) return type(x) end").eval::<Function>()?;
+    assert_eq!(f.call::<String>(NumberOrString::String("5".into()))?, "string");
+    assert_eq!(f.call::<String>(NumberOrString::Number(5.0))?, "number");
+
+    Ok(())
+}
+
+#[test]
+fn test_strict_int() -> Result<()> {
+    let lua = Lua::new();
+
+    assert_eq!(lua.unpack::<StrictInt<i32>>(Value::Integer(3))?, StrictInt(3));
+    assert_eq!(lua.unpack::<StrictInt<i32>>(Value::Number(3.0))?, StrictInt(3));
+
+    let err = lua.unpack::<StrictInt<i32>>(Value::Number(3.5)).unwrap_err();
+    assert!(err.to_string().contains("number has fractional part"), "{err}");
+    // The lenient conversion still truncates
+    assert_eq!(lua.unpack::<i32>(Value::Number(3.5))?, 3);
+
+    let err = lua.unpack::<StrictInt<u8>>(Value::Integer(256)).unwrap_err();
+    assert!(err.to_string().contains("out of range"), "{err}");
+    assert!(lua.unpack::<StrictInt<u32>>(Value::Integer(-1)).is_err());
+    assert!(lua.unpack::<StrictInt<i64>>(Value::Number(1e20)).is_err());
+    for n in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+        let err = lua.unpack::<StrictInt<i64>>(Value::Number(n)).unwrap_err();
+        assert!(err.to_string().contains("expected finite number"), "{err}");
+    }
+
+    let s = lua.create_string("3")?;
+    assert!(lua.unpack::<StrictInt<i32>>(Value::String(s)).is_err());
+
+    assert_eq!(lua.pack(StrictInt(42u16))?, Value::Integer(42));
+
+    Ok(())
+}
+
+#[test]
+fn test_digest() -> Result<()> {
+    let lua = Lua::new();
+
+    let digest = Digest([0x01, 0x23, 0xab, 0xff]);
+
+    // Raw bytes
+    let v = lua.pack(digest)?;
+    assert_eq!(v.as_string().unwrap().as_bytes(), b"\x01\x23\xab\xff".as_slice());
+    assert_eq!(lua.unpack::<Digest<4>>(v)?, digest);
+
+    // Hex
+    let v = lua.pack(digest.to_hex())?;
+    assert_eq!(v.to_string()?, "0123abff");
+    assert_eq!(lua.unpack::<Digest<4>>(v)?, digest);
+    assert_eq!(lua.load("'0123ABFF'").eval::<Digest<4>>()?, digest);
+
+    // Wrong length
+    let err = lua.load("'abc'").eval::<Digest<4>>().unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("expected 4 bytes or 8 hex digits, got 3 bytes"),
+        "{err}"
+    );
+    assert!(lua.load("'0123abfx'").eval::<Digest<4>>().is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_nonzero() -> Result<()> {
+    let lua = Lua::new();
+
+    let id = NonZeroU64::new(1 << 52).unwrap();
+    let f = lua.create_function(|_, id: NonZeroU64| Ok(id))?;
+    assert_eq!(f.call::<NonZeroU64>(id)?, id);
+    assert_eq!(lua.pack(NonZeroI32::new(-5).unwrap())?, Value::Integer(-5));
+
+    let err = lua.unpack::<NonZeroU32>(Value::Integer(0)).unwrap_err();
+    assert!(err.to_string().contains("value must be non-zero"), "{err}");
+    assert!(lua.unpack::<NonZeroI8>(Value::Integer(0)).is_err());
+
+    // Range checks are the same as for the inner integer
+    let err = lua.unpack::<NonZeroU8>(Value::Integer(256)).unwrap_err();
+    assert!(err.to_string().contains("out of range"), "{err}");
+
+    Ok(())
+}
+
+#[test]
+fn test_net_addr() -> Result<()> {
+    let lua = Lua::new();
+
+    let v4 = IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1));
+    let v = lua.pack(v4)?;
+    assert_eq!(v.to_string()?, "127.0.0.1");
+    assert_eq!(lua.unpack::<IpAddr>(v)?, v4);
+
+    let v6 = IpAddr::V6(Ipv6Addr::LOCALHOST);
+    let v = lua.pack(v6)?;
+    assert_eq!(v.to_string()?, "::1");
+    assert_eq!(lua.unpack::<IpAddr>(v.clone())?, v6);
+    assert_eq!(lua.unpack::<Ipv6Addr>(v)?, Ipv6Addr::LOCALHOST);
+
+    // Socket address carries the port in the same string
+    let addr = SocketAddr::new(v4, 8080);
+    let v = lua.pack(addr)?;
+    assert_eq!(v.to_string()?, "127.0.0.1:8080");
+    assert_eq!(lua.unpack::<SocketAddr>(v)?, addr);
+    let addr6 = lua.load("'[::1]:443'").eval::<SocketAddr>()?;
+    assert_eq!(addr6, SocketAddr::new(v6, 443));
+
+    let err = lua.load("'256.0.0.1'").eval::<Ipv4Addr>().unwrap_err();
+    assert!(err.to_string().contains("invalid IPv4 address syntax"), "{err}");
+    assert!(lua.load("'127.0.0.1'").eval::<SocketAddr>().is_err());
+    assert!(lua.unpack::<IpAddr>(Value::Integer(1)).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_rect() -> Result<()> {
+    let lua = Lua::new();
+
+    let rect = Rect::new(1.0, 2.0, 30.0, 40.0)?;
+    assert_eq!(lua.load("{x = 1, y = 2, w = 30, h = 40}").eval::<Rect>()?, rect);
+    assert_eq!(lua.load("{1, 2, 30, 40}").eval::<Rect>()?, rect);
+
+    let t = lua.pack(rect)?;
+    let t = t.as_table().unwrap();
+    assert_eq!(t.get::<f64>("w")?, 30.0);
+    assert_eq!(lua.unpack::<Rect>(Value::Table(t.clone()))?, rect);
+
+    let err = lua
+        .load("{x = 0, y = 0, w = -1, h = 1}")
+        .eval::<Rect>()
+        .unwrap_err();
+    assert!(err.to_string().contains("width -1 must not be negative"), "{err}");
+    assert!(lua.load("{0, 0, 1, -1}").eval::<Rect>().is_err());
+    assert!(lua.load("{0, 0, 1}").eval::<Rect>().is_err());
+    assert!(lua.load("'0,0,1,1'").eval::<Rect>().is_err());
+
+    let err = Rect::new(0.0, 0.0, 1.0, f64::NAN).unwrap_err();
+    assert!(matches!(err, Error::RuntimeError(ref msg) if msg == "height NaN must not be negative"));
+
+    Ok(())
+}
+
+#[test]
+fn test_rate() -> Result<()> {
+    let lua = Lua::new();
+
+    lua.globals().set("r", Rate(2.5))?;
+    assert_eq!(lua.load("r").eval::<f64>()?, 2.5);
+
+    assert_eq!(lua.load("10").eval::<Rate>()?, Rate(10.0));
+    assert_eq!(lua.load("{ interval = 0.25 }").eval::<Rate>()?, Rate(4.0));
+    assert_eq!(lua.load("{ interval = 2 }").eval::<Rate>()?, Rate(0.5));
+
+    let err = lua.load("{ interval = 0 }").eval::<Rate>().unwrap_err();
+    assert!(err.to_string().contains("interval must not be zero"), "{err}");
+    assert!(lua.load("{}").eval::<Rate>().is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_vec_deque_linked_list() -> Result<()> {
+    let lua = Lua::new();
+
+    let mut queue = VecDeque::from([2, 3]);
+    queue.push_front(1);
+    queue.push_back(4);
+    let v = lua.pack(queue.clone())?;
+    assert_eq!(v.as_table().unwrap(), &[1, 2, 3, 4]);
+    assert_eq!(lua.unpack::<VecDeque<i32>>(v)?, queue);
+
+    let list = LinkedList::from(["a".to_string(), "b".to_string(), "c".to_string()]);
+    let v = lua.pack(list.clone())?;
+    assert_eq!(v.as_table().unwrap(), &["a", "b", "c"]);
+    assert_eq!(lua.unpack::<LinkedList<String>>(v)?, list);
+
+    // Order is preserved front to back
+    let popped = lua
+        .load("local q = ...; return table.remove(q, 1), q")
+        .call::<(i32, VecDeque<i32>)>(queue)?;
+    assert_eq!(popped, (1, VecDeque::from([2, 3, 4])));
+
+    let err = lua.unpack::<VecDeque<i32>>(Value::Integer(1)).unwrap_err();
+    assert!(err.to_string().contains("expected table"), "{err}");
+    assert!(lua.unpack::<LinkedList<i32>>(Value::Boolean(true)).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_sparse_array() -> Result<()> {
+    let lua = Lua::new();
+
+    let sparse = SparseArray(HashMap::from([(1, "a"), (3, "c"), (10, "j")]));
+    let t = lua.pack(sparse)?;
+    let t = t.as_table().unwrap();
+    assert_eq!(t.get::<String>(1)?, "a");
+    assert_eq!(t.get::<Value>(2)?, Value::Nil);
+    assert_eq!(t.get::<String>(3)?, "c");
+    assert_eq!(t.get::<Value>(9)?, Value::Nil);
+    assert_eq!(t.get::<String>(10)?, "j");
+    assert_eq!(t.pairs::<Value, Value>().count(), 3);
+
+    let SparseArray(map) = lua.unpack::<SparseArray<String>>(Value::Table(t.clone()))?;
+    let mut keys = map.keys().copied().collect::<Vec<_>>();
+    keys.sort();
+    assert_eq!(keys, [1, 3, 10]);
+    assert_eq!(map[&10], "j");
+
+    assert!(lua.load("{ [0] = 1 }").eval::<SparseArray<i32>>().is_err());
+    assert!(lua.load("{ x = 1 }").eval::<SparseArray<i32>>().is_err());
+    assert!(lua.pack(SparseArray(HashMap::from([(0, 1)]))).is_err());
+
+    Ok(())
+}
+
+#[cfg(feature = "serde_json")]
+#[test]
+fn test_serde_json_value() -> Result<()> {
+    use serde_json::json;
+
+    let lua = Lua::new();
+
+    let value = json!({
+        "name": "mlua",
+        "version": 1,
+        "ratio": 0.5,
+        "tags": ["lua", "rust"],
+        "nested": { "ok": true, "list": [{ "id": 1 }, { "id": 2 }] },
+        "missing": null,
+    });
+    lua.globals().set("v", value)?;
+    lua.load(
+        r#"
+        assert(v.name == "mlua" and v.version == 1 and v.ratio == 0.5)
+        assert(#v.tags == 2 and v.tags[2] == "rust")
+        assert(v.nested.ok == true and v.nested.list[2].id == 2)
+        assert(v.missing == nil)
+    "#,
+    )
+    .exec()?;
+
+    let value = lua
+        .load(r#"{ a = { 1, 2.5, "x" }, b = { c = false, d = {} }, [1.5] = 3 }"#)
+        .eval::<serde_json::Value>()?;
+    assert_eq!(
+        value,
+        json!({ "a": [1, 2.5, "x"], "b": { "c": false, "d": [] }, "1.5": 3 })
+    );
+    // Integer vs float distinction is preserved
+    #[cfg(any(feature = "lua54", feature = "lua53"))]
+    {
+        assert!(lua.load("1").eval::<serde_json::Value>()?.is_i64());
+        assert!(lua.load("1.0").eval::<serde_json::Value>()?.is_f64());
+    }
+
+    assert!(lua.load("{ f = print }").eval::<serde_json::Value>().is_err());
+    assert!(lua.load("0/0").eval::<serde_json::Value>().is_err());
+    assert!(lua.load("{ [true] = 1 }").eval::<serde_json::Value>().is_err());
+
+    // `arbitrary_precision` (enabled in dev-dependencies) allows numbers that overflow a float
+    let big = serde_json::from_str::<serde_json::Value>("1e400").unwrap();
+    let err = lua.pack(big).unwrap_err();
+    assert!(matches!(err, Error::ToLuaConversionError { .. }), "{err:?}");
+
+    Ok(())
+}
+
+#[test]
+fn test_binary_heap() -> Result<()> {
+    let lua = Lua::new();
+
+    let heap = BinaryHeap::from([5, 1, 8, 3, 9, 2]);
+    let v = lua.pack(heap.clone())?;
+    let t = v.as_table().unwrap();
+    assert_eq!(t.raw_len(), 6);
+
+    let heap2 = lua.unpack::<BinaryHeap<i32>>(v)?;
+    assert_eq!(heap2.into_sorted_vec(), heap.into_sorted_vec());
+
+    let heap3 = lua.load("{ 4, 10, 7 }").eval::<BinaryHeap<i32>>()?;
+    assert_eq!(heap3.peek(), Some(&10));
+
+    let err = lua.unpack::<BinaryHeap<i32>>(Value::Integer(1)).unwrap_err();
+    assert!(err.to_string().contains("expected table"), "{err}");
+
+    Ok(())
+}
+
+#[test]
+fn test_quantity() -> Result<()> {
+    let lua = Lua::new();
+
+    let q = lua.load("{ value = 2, unit = 'h' }").eval::<Quantity>()?;
+    assert_eq!(q, Quantity::new(2.0, "h"));
+    assert_eq!(q.convert_to("min")?, Quantity::new(120.0, "min"));
+    assert_eq!(Quantity::new(1.0, "lb").convert_to("g")?.value, 453.59237);
+    assert_eq!(Quantity::new(3.0, "ft").convert_to("in")?.value.round(), 36.0);
+
+    let err = q.convert_to("kg").unwrap_err();
+    assert!(err.to_string().contains("incompatible units"), "{err}");
+    assert!(q.convert_to("parsec").is_err());
+
+    let t = lua.pack(Quantity::new(5.0, "kg"))?;
+    let t = t.as_table().unwrap();
+    assert_eq!(t.get::<f64>("value")?, 5.0);
+    assert_eq!(t.get::<String>("unit")?, "kg");
+
+    assert!(lua.load("{ value = 1, unit = '' }").eval::<Quantity>().is_err());
+    assert!(lua.load("{ value = 1 }").eval::<Quantity>().is_err());
+    assert!(lua
+        .load("{ value = 'x', unit = 'm' }")
+        .eval::<Quantity>()
+        .is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_bytes_into_lua() -> Result<()> {
+    let lua = Lua::new();
+
+    let f = lua
+        .load("function(s) return type(s), #s end")
+        .eval::<Function>()?;
+
+    let bytes: &[u8] = &[1u8, 2, 3];
+    assert_eq!(
+        f.call::<(String, usize)>(Cow::Borrowed(bytes))?,
+        ("string".into(), 3)
+    );
+    assert_eq!(
+        f.call::<(String, usize)>(Cow::<[u8]>::Owned(vec![0, 255]))?,
+        ("string".into(), 2)
+    );
+    assert_eq!(f.call::<(String, usize)>(BStr::new(bytes))?, ("string".into(), 3));
+    assert_eq!(
+        f.call::<(String, usize)>(BString::from(vec![1u8, 2, 3]))?,
+        ("string".into(), 3)
+    );
+
+    let v = lua.pack(Cow::Borrowed(bytes))?;
+    assert_eq!(v.as_string().unwrap().as_bytes(), bytes);
+
+    // Plain slices are still converted into tables
+    assert_eq!(f.call::<(String, usize)>(bytes)?, ("table".into(), 3));
+
+    Ok(())
+}
+
+#[test]
+fn test_range() -> Result<()> {
+    let lua = Lua::new();
+
+    let v = lua.pack(0..10)?;
+    let t = v.as_table().unwrap();
+    assert_eq!((t.get::<i32>("start")?, t.get::<i32>("end")?), (0, 10));
+    assert_eq!(t.get::<Option<bool>>("inclusive")?, None);
+    assert_eq!(lua.unpack::<std::ops::Range<i32>>(v)?, 0..10);
+
+    let v = lua.pack(0..=9)?;
+    let t = v.as_table().unwrap();
+    assert_eq!((t.get::<i32>("start")?, t.get::<i32>("end")?), (0, 9));
+    assert!(t.get::<bool>("inclusive")?);
+    assert_eq!(lua.unpack::<std::ops::RangeInclusive<i32>>(v)?, 0..=9);
+
+    let err = lua
+        .load("{ start = 1 }")
+        .eval::<std::ops::Range<i32>>()
+        .unwrap_err();
+    assert!(err.to_string().contains("missing field `end`"), "{err}");
+    assert!(lua
+        .load("{ ['end'] = 1 }")
+        .eval::<std::ops::RangeInclusive<i32>>()
+        .is_err());
+    assert!(lua.load("'1..2'").eval::<std::ops::Range<i32>>().is_err());
+
+    // The `inclusive` flag must match the target range type
+    let err = (lua.unpack::<std::ops::Range<i32>>(lua.pack(0..=9)?)).unwrap_err();
+    assert!(err.to_string().contains("expected exclusive range"), "{err}");
+    let err = (lua.load("{ start = 0, ['end'] = 10, inclusive = false }"))
+        .eval::<std::ops::RangeInclusive<i32>>()
+        .unwrap_err();
+    assert!(err.to_string().contains("expected inclusive range"), "{err}");
+    let v = lua
+        .load("{ start = 0, ['end'] = 10, inclusive = false }")
+        .eval::<std::ops::Range<i32>>()?;
+    assert_eq!(v, 0..10);
+
+    // The `inclusive` flag must be a boolean
+    for flag in ["'no'", "0"] {
+        let code = format!("{{ start = 0, ['end'] = 10, inclusive = {flag} }}");
+        assert!(lua.load(&code).eval::<std::ops::Range<i32>>().is_err());
+        assert!(lua.load(&code).eval::<std::ops::RangeInclusive<i32>>().is_err());
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_bound() -> Result<()> {
+    let lua = Lua::new();
+
+    for bound in [Bound::Included(5), Bound::Excluded(7), Bound::Unbounded] {
+        let v = lua.pack(bound)?;
+        assert_eq!(lua.unpack::<Bound<i32>>(v)?, bound);
+    }
+
+    let t = lua.pack(Bound::Excluded("x"))?;
+    let t = t.as_table().unwrap();
+    assert_eq!(t.get::<String>("kind")?, "excluded");
+    assert_eq!(t.get::<String>("value")?, "x");
+    let t = lua.pack(Bound::<i32>::Unbounded)?;
+    assert!(t.as_table().unwrap().get::<Value>("value")?.is_nil());
+
+    assert_eq!(
+        lua.load("{ kind = 'included', value = 1 }")
+            .eval::<Bound<i32>>()?,
+        Bound::Included(1)
+    );
+    let err = lua.load("{ kind = 'open' }").eval::<Bound<i32>>().unwrap_err();
+    assert!(err.to_string().contains("unknown bound kind `open`"), "{err}");
+    let err = lua
+        .load("{ kind = 'excluded' }")
+        .eval::<Bound<i32>>()
+        .unwrap_err();
+    assert!(err.to_string().contains("missing field `value`"), "{err}");
+    let err = lua.load("{ value = 1 }").eval::<Bound<i32>>().unwrap_err();
+    assert!(err.to_string().contains("missing field `kind`"), "{err}");
+    assert!(lua.load("1").eval::<Bound<i32>>().is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_cell() -> Result<()> {
+    use std::cell::{Cell, RefCell};
+
+    let lua = Lua::new();
+
+    let counter = Cell::new(5i64);
+    lua.globals().set("counter", &counter)?;
+    counter.set(6);
+    // Lua keeps the snapshot taken at conversion time
+    assert_eq!(lua.load("counter").eval::<i64>()?, 5);
+    let counter = lua.globals().get::<Cell<i64>>("counter")?;
+    assert_eq!(counter.get(), 5);
+    lua.globals().set("counter", counter)?;
+    assert_eq!(lua.globals().get::<Cell<i64>>("counter")?.into_inner(), 5);
+
+    let name = RefCell::new("lua".to_string());
+    lua.globals().set("name", &name)?;
+    name.borrow_mut().push_str("jit");
+    assert_eq!(lua.load("name").eval::<String>()?, "lua");
+    let name = lua.globals().get::<RefCell<String>>("name")?;
+    assert_eq!(*name.borrow(), "lua");
+    lua.globals().set("name", name)?;
+    assert_eq!(lua.globals().get::<RefCell<String>>("name")?.into_inner(), "lua");
+
+    // Mutably borrowed cells cannot be converted
+    let cell = RefCell::new(1);
+    let _guard = cell.borrow_mut();
+    let err = lua.globals().set("x", &cell).unwrap_err();
+    assert!(err.to_string().contains("already mutably borrowed"), "{err}");
+
+    Ok(())
+}
+
+#[test]
+fn test_reverse() -> Result<()> {
+    use std::cmp::Reverse;
+
+    let lua = Lua::new();
+
+    let mut heap = BinaryHeap::from([Reverse(3), Reverse(1), Reverse(2)]);
+    lua.globals().set("min", heap.pop())?;
+    assert_eq!(lua.load("min").eval::<i32>()?, 1);
+    assert_eq!(
+        lua.globals().get::<Option<Reverse<i32>>>("min")?,
+        Some(Reverse(1))
+    );
+
+    let f = lua.create_function(|_, x: Reverse<String>| Ok(x))?;
+    assert_eq!(f.call::<Reverse<String>>("abc")?, Reverse("abc".to_string()));
+
+    Ok(())
+}
+
+#[test]
+fn test_pairs_vec() -> Result<()> {
+    let lua = Lua::new();
+
+    let mut pairs = lua.load("{ a = 1, b = 2, c = 3 }").eval::<Vec<(String, i64)>>()?;
+    pairs.sort();
+    assert_eq!(pairs, vec![("a".into(), 1), ("b".into(), 2), ("c".into(), 3)]);
+
+    // Keys that collide after conversion are all kept
+    let mut pairs = lua
+        .load("{ [1] = 'int', ['1'] = 'str' }")
+        .eval::<Vec<(String, String)>>()?;
+    pairs.sort();
+    assert_eq!(
+        pairs,
+        vec![("1".into(), "int".into()), ("1".into(), "str".into())]
+    );
+
+    let t = lua.pack(vec![("x", 1), ("y", 2), ("x", 3)])?;
+    let t = t.as_table().unwrap();
+    assert_eq!(t.get::<i64>("x")?, 3);
+    assert_eq!(t.get::<i64>("y")?, 2);
+
+    let err = lua.load("{ a = 'x' }").eval::<Vec<(String, i64)>>().unwrap_err();
+    assert!(err.to_string().contains("bad value at key \"a\""), "{err}");
+    assert!(lua.load("1").eval::<Vec<(String, i64)>>().is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_mut_ref_into_lua() -> Result<()> {
+    fn type_of(lua: &Lua, value: impl IntoLua) -> Result<String> {
+        lua.globals().get::<Function>("type")?.call(value)
+    }
+
+    let lua = Lua::new();
+
+    let mut table = lua.create_table()?;
+    let mut func = lua.create_function(|_, ()| Ok(()))?;
+    let mut value = Value::Boolean(true);
+    let mut s = String::from("abc");
+    let mut list = [1, 2, 3];
+    assert_eq!(type_of(&lua, &mut table)?, "table");
+    assert_eq!(type_of(&lua, &mut func)?, "function");
+    assert_eq!(type_of(&lua, &mut value)?, "boolean");
+    assert_eq!(type_of(&lua, s.as_mut_str())?, "string");
+    assert_eq!(type_of(&lua, &mut list[..])?, "table");
+
+    lua.globals().set("t", &mut table)?;
+    assert_eq!(lua.globals().get::<Table>("t")?, table);
+
+    Ok(())
+}
+
+#[test]
+fn test_shared_str_into_lua() -> Result<()> {
+    let lua = Lua::new();
+
+    let f = lua.load("function(s) return type(s), s end").eval::<Function>()?;
+    let arc: std::sync::Arc<str> = "hi".into();
+    assert_eq!(f.call::<(String, String)>(arc)?, ("string".into(), "hi".into()));
+    let rc: std::rc::Rc<str> = "hey".into();
+    assert_eq!(f.call::<(String, String)>(rc)?, ("string".into(), "hey".into()));
+
+    let v = lua.pack(std::sync::Arc::<str>::from("hi"))?;
+    assert_eq!(v.as_string().unwrap(), "hi");
+
+    Ok(())
+}
+
+#[cfg(feature = "uuid")]
+#[test]
+fn test_uuid() -> Result<()> {
+    use uuid::Uuid;
+
+    let lua = Lua::new();
+
+    let id = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+    lua.globals().set("id", id)?;
+    assert_eq!(
+        lua.load("id").eval::<String>()?,
+        "67e55044-10b1-426f-9247-bb680e5fe0c8"
+    );
+    assert_eq!(lua.load("id").eval::<Uuid>()?, id);
+    assert_eq!(lua.load("string.upper(id)").eval::<Uuid>()?, id);
+    assert_eq!(lua.load("id:gsub('-', '')").eval::<Uuid>()?, id);
+
+    // Raw bytes
+    let raw = lua.create_string(id.as_bytes())?;
+    assert_eq!(lua.unpack::<Uuid>(Value::String(raw))?, id);
+
+    let err = lua.load("'not-a-uuid'").eval::<Uuid>().unwrap_err();
+    assert!(err.to_string().contains("invalid uuid"), "{err}");
+    assert!(lua.load("123").eval::<Uuid>().is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_collection_error_preview() -> Result<()> {
+    let lua = Lua::new();
+
+    let err = lua.load("{1, 2, 3, 4, 'five'}").eval::<Vec<i64>>().unwrap_err();
+    let msg = err.to_string();
+    assert!(msg.contains("bad element #5 (\"five\")"), "{msg}");
+
+    let err = lua.load("{1, 2, {}}").eval::<VecDeque<i64>>().unwrap_err();
+    assert!(err.to_string().contains("bad element #3 (table)"), "{err}");
+
+    // Long strings are truncated
+    let err = lua
+        .load("{string.rep('x', 1000)}")
+        .eval::<Vec<i64>>()
+        .unwrap_err();
+    let msg = err.to_string();
+    assert!(msg.contains(&format!("(\"{}\"...)", "x".repeat(32))), "{msg}");
+    assert!(msg.len() < 200, "{msg}");
+
+    let err = (lua.load("{a = 1, b = 'x'}").eval::<HashMap<String, i64>>()).unwrap_err();
+    assert!(
+        err.to_string().contains("bad value at key \"b\" (\"x\")"),
+        "{err}"
+    );
+    let err = (lua.load("{[true] = 1}").eval::<BTreeMap<String, i64>>()).unwrap_err();
+    assert!(err.to_string().contains("bad key (true)"), "{err}");
+    let err = (lua.load("{[{}] = true}").eval::<HashSet<i64>>()).unwrap_err();
+    assert!(err.to_string().contains("bad key (table)"), "{err}");
+
+    // The original error is kept as the cause
+    let err = lua.load("{1, 'x'}").eval::<Vec<i64>>().unwrap_err();
+    match err {
+        Error::WithContext { context, cause } => {
+            assert_eq!(context, "bad element #2 (\"x\")");
+            assert!(
+                matches!(*cause, Error::FromLuaConversionError { .. }),
+                "{cause:?}"
+            );
+        }
+        err => panic!("expected WithContext, got {err:?}"),
+    }
+
+    Ok(())
+}
+
+#[cfg(feature = "bytes")]
+#[test]
+fn test_bytes() -> Result<()> {
+    use bytes::{Bytes, BytesMut};
+
+    let lua = Lua::new();
+
+    let data = Bytes::from_static(b"\x00\xff\x01bin\x00ary\x80");
+    lua.globals().set("data", data.clone())?;
+    assert_eq!(lua.load("#data").eval::<usize>()?, 11);
+    assert_eq!(lua.load("data").eval::<Bytes>()?, data);
+    assert_eq!(
+        lua.load("data .. '!'").eval::<BytesMut>()?,
+        &b"\x00\xff\x01bin\x00ary\x80!"[..]
+    );
+
+    let mut buf = BytesMut::with_capacity(4);
+    buf.extend_from_slice(b"a\0b");
+    let s = lua.convert::<String>(buf)?;
+    assert_eq!(s.as_bytes(), b"a\0b");
+
+    assert_eq!(lua.convert::<Bytes>(42)?, Bytes::from_static(b"42"));
+    assert!(lua.convert::<Bytes>(true).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_cow_str_from_lua() -> Result<()> {
+    let lua = Lua::new();
+
+    let s = lua.load("'hello'").eval::<Cow<'static, str>>()?;
+    assert!(matches!(s, Cow::Owned(_)));
+    assert_eq!(s, "hello");
+    assert_eq!(lua.convert::<Cow<'static, str>>(42)?, "42");
+    assert!(lua.convert::<Cow<'static, str>>(true).is_err());
+
+    // Round trip via a function argument (uses the stack fast path)
+    let f = lua.create_function(|_, s: Cow<'static, str>| Ok(s))?;
+    assert_eq!(f.call::<Cow<'static, str>>(Cow::Borrowed("abc"))?, "abc");
+
+    Ok(())
+}
+
+#[test]
+fn test_cow_cstr_from_lua() -> Result<()> {
+    let lua = Lua::new();
+
+    let s = lua.load("'hello'").eval::<Cow<'static, CStr>>()?;
+    assert!(matches!(s, Cow::Owned(_)));
+    assert_eq!(s.to_bytes(), b"hello");
+
+    let f = lua.create_function(|_, s: Cow<'static, CStr>| Ok(s))?;
+    let s = f.call::<Cow<'static, CStr>>(Cow::Borrowed(c"abc"))?;
+    assert_eq!(&*s, c"abc");
+
+    let err = lua.load("'a\\0b'").eval::<Cow<'static, CStr>>().unwrap_err();
+    assert!(err.to_string().contains("invalid C-style string"), "{err}");
+
+    Ok(())
+}
+
+#[cfg(feature = "indexmap")]
+#[test]
+fn test_indexmap() -> Result<()> {
+    use indexmap::IndexMap;
+
+    let lua = Lua::new();
+
+    // Sequence keys keep their order
+    let map = (1..=10).map(|i| (i, i * 10)).collect::<IndexMap<i64, i64>>();
+    lua.globals().set("map", map.clone())?;
+    assert_eq!(lua.load("#map").eval::<usize>()?, 10);
+    let map2 = lua.load("map").eval::<IndexMap<i64, i64>>()?;
+    assert!(map.iter().eq(map2.iter()));
+
+    let map = lua
+        .load("{a = 1, b = 2}")
+        .eval::<IndexMap<std::string::String, i64>>()?;
+    assert_eq!((map["a"], map["b"]), (1, 2));
+    assert!(lua.convert::<IndexMap<i64, i64>>("x").is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_system_time() -> Result<()> {
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    let lua = Lua::new();
+
+    let now = SystemTime::now();
+    lua.globals().set("now", now)?;
+    let secs = lua.load("now").eval::<f64>()?;
+    let expected = now.duration_since(UNIX_EPOCH).unwrap().as_secs_f64();
+    assert!((secs - expected).abs() < 1e-3);
+
+    let now2 = lua.load("now").eval::<SystemTime>()?;
+    let diff = match now2.duration_since(now) {
+        Ok(d) => d,
+        Err(err) => err.duration(),
+    };
+    assert!(diff < Duration::from_millis(1));
+
+    // Before the epoch
+    let t = UNIX_EPOCH - Duration::from_millis(1500);
+    assert_eq!(lua.convert::<f64>(t)?, -1.5);
+    assert_eq!(lua.convert::<SystemTime>(-1.5)?, t);
+    assert_eq!(
+        lua.convert::<SystemTime>(60)?,
+        UNIX_EPOCH + Duration::from_secs(60)
+    );
+
+    assert!(lua.convert::<SystemTime>(f64::NAN).is_err());
+    assert!(lua.convert::<SystemTime>(f64::INFINITY).is_err());
+    assert!(lua.convert::<SystemTime>("soon").is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_collection_from_lua_sparse_and_dense() -> Result<()> {
+    let lua = Lua::new();
+
+    // Dense sequence
+    let table = lua.create_sequence_from(1..=1000)?;
+    let expected = (1..=1000).collect::<Vec<i64>>();
+    assert_eq!(lua.unpack::<Vec<i64>>(Value::Table(table.clone()))?, expected);
+    assert!(lua
+        .unpack::<VecDeque<i64>>(Value::Table(table.clone()))?
+        .iter()
+        .eq(&expected));
+    let heap = lua.unpack::<BinaryHeap<i64>>(Value::Table(table.clone()))?;
+    assert_eq!(heap.into_sorted_vec(), expected);
+    let map = lua.unpack::<HashMap<i64, i64>>(Value::Table(table))?;
+    assert_eq!(map.len(), 1000);
+    assert!((1..=1000).all(|i| map[&i] == i));
+
+    // Sparse table: the sequence stops at the first hole
+    let table = lua.load("{1, 2, nil, 4, [100] = 100}").eval::<Table>()?;
+    assert_eq!(lua.unpack::<Vec<i64>>(Value::Table(table.clone()))?, vec![1, 2]);
+    let map = lua.unpack::<BTreeMap<i64, i64>>(Value::Table(table.clone()))?;
+    assert_eq!(
+        map.into_iter().collect::<Vec<_>>(),
+        vec![(1, 1), (2, 2), (4, 4), (100, 100)]
+    );
+    let map = lua.unpack::<HashMap<i64, i64>>(Value::Table(table))?;
+    assert_eq!(map.len(), 4);
+
+    // Empty table
+    assert!(lua
+        .unpack::<Vec<i64>>(Value::Table(lua.create_table()?))?
+        .is_empty());
+
+    // Table with a huge border but only a few keys
+    let keys = (0..=36).map(|i| format!("[{}] = {i}", 1_i64 << i));
+    let code = format!("return {{{}}}", keys.collect::<Vec<_>>().join(", "));
+    let table = lua.load(code).eval::<Table>()?;
+    assert!(table.raw_len() > 1 << 32);
+    assert_eq!(lua.unpack::<Vec<i64>>(Value::Table(table.clone()))?, vec![0, 1]);
+    let map = lua.unpack::<HashMap<i64, i64>>(Value::Table(table))?;
+    assert_eq!(map.len(), 37);
+
+    Ok(())
+}
+
+#[cfg(feature = "arrayvec")]
+#[test]
+fn test_arrayvec() -> Result<()> {
+    use arrayvec::ArrayVec;
+
+    let lua = Lua::new();
+
+    let v = lua.load("{1, 2, 3}").eval::<ArrayVec<i32, 4>>()?;
+    assert_eq!(v.as_slice(), &[1, 2, 3]);
+    let v = lua.load("{1, 2, 3, 4}").eval::<ArrayVec<i32, 4>>()?;
+    let t = lua.convert::<Table>(v)?;
+    assert_eq!(
+        t.sequence_values::<i32>().collect::<Result<Vec<_>>>()?,
+        vec![1, 2, 3, 4]
+    );
+
+    let err = lua
+        .load("{1, 2, 3, 4, 5}")
+        .eval::<ArrayVec<i32, 4>>()
+        .unwrap_err();
+    assert!(
+        err.to_string().contains("too many elements, capacity is 4"),
+        "{err}"
+    );
+    let err = lua.load("{1, 'x'}").eval::<ArrayVec<i32, 4>>().unwrap_err();
+    assert!(err.to_string().contains("bad element #2"), "{err}");
+    assert!(lua.convert::<ArrayVec<i32, 4>>(123).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_phantom_data() -> Result<()> {
+    use std::marker::PhantomData;
+
+    struct Tagged<T, U> {
+        value: T,
+        _marker: PhantomData<U>,
+    }
+
+    impl<T: IntoLua, U> IntoLua for Tagged<T, U> {
+        fn into_lua(self, lua: &Lua) -> Result<Value> {
+            let t = lua.create_table()?;
+            t.set("value", self.value)?;
+            t.set("marker", self._marker)?;
+            Ok(Value::Table(t))
+        }
+    }
+
+    fn pack_all<T: IntoLua>(lua: &Lua, items: Vec<T>) -> Result<Table> {
+        lua.create_sequence_from(items)
+    }
+
+    let lua = Lua::new();
+
+    let t = pack_all(
+        &lua,
+        vec![Tagged::<_, std::convert::Infallible> {
+            value: 1,
+            _marker: PhantomData,
+        }],
+    )?;
+    let item = t.get::<Table>(1)?;
+    assert_eq!(item.get::<i64>("value")?, 1);
+    assert_eq!(item.get::<Value>("marker")?, Value::Nil);
+
+    assert_eq!(lua.pack(PhantomData::<str>)?, Value::Nil);
+    lua.unpack::<PhantomData<str>>(Value::Nil)?;
+    assert!(lua.unpack::<PhantomData<str>>(Value::Integer(1)).is_err());
+
+    // `Infallible` satisfies `IntoLua` bounds
+    let res: std::result::Result<i64, std::convert::Infallible> = Ok(5);
+    let f = lua.create_function(move |_, ()| Ok(res))?;
+    assert_eq!(f.call::<i64>(())?, 5);
+
+    Ok(())
+}
+
+#[test]
+fn test_finite_float() -> Result<()> {
+    let lua = Lua::new();
+
+    assert_eq!(lua.load("1.5").eval::<FiniteFloat<f64>>()?, FiniteFloat(1.5));
+    assert_eq!(lua.load("'2'").eval::<FiniteFloat<f32>>()?, FiniteFloat(2.0));
+
+    let err = lua.load("0/0").eval::<FiniteFloat<f64>>().unwrap_err();
+    assert!(
+        err.to_string().contains("expected finite number, got NaN"),
+        "{err}"
+    );
+    let err = lua.load("1/0").eval::<FiniteFloat<f64>>().unwrap_err();
+    assert!(
+        err.to_string().contains("expected finite number, got inf"),
+        "{err}"
+    );
+    let err = lua.load("-1/0").eval::<FiniteFloat<f32>>().unwrap_err();
+    assert!(
+        err.to_string().contains("expected finite number, got -inf"),
+        "{err}"
+    );
+
+    // Plain floats stay lenient
+    assert!(lua.load("0/0").eval::<f64>()?.is_nan());
+
+    lua.globals().set("x", FiniteFloat(0.5f32))?;
+    assert_eq!(lua.load("x").eval::<f64>()?, 0.5);
+
+    Ok(())
+}
+
+#[test]
+fn test_wrapping() -> Result<()> {
+    use std::num::Wrapping;
+
+    let lua = Lua::new();
+
+    let counter = Wrapping(u32::MAX) + Wrapping(2);
+    lua.globals().set("counter", counter)?;
+    assert_eq!(lua.load("counter").eval::<i64>()?, 1);
+    assert_eq!(lua.globals().get::<Wrapping<u32>>("counter")?, Wrapping(1));
+
+    let inc = lua.create_function(|_, n: Wrapping<u8>| Ok(n + Wrapping(1)))?;
+    assert_eq!(inc.call::<Wrapping<u8>>(255)?, Wrapping(0));
+
+    // Out-of-range values are rejected by the inner conversion
+    let err = lua.load("256").eval::<Wrapping<u8>>().unwrap_err();
+    assert!(err.to_string().contains("out of range"), "{err}");
+    let err = inc.call::<Wrapping<u8>>(-1).unwrap_err();
+    assert!(err.to_string().contains("out of range"), "{err}");
+
+    Ok(())
+}
+
+#[cfg(feature = "half")]
+#[test]
+fn test_half() -> Result<()> {
+    use half::{bf16, f16};
+
+    let lua = Lua::new();
+
+    // Values are passed to Lua as regular numbers
+    lua.globals().set("h", f16::from_f32(1.5))?;
+    assert_eq!(lua.load("h").eval::<f64>()?, 1.5);
+    assert_eq!(lua.load("h * 2").eval::<f16>()?, f16::from_f32(3.0));
+
+    // Rounding to the nearest representable value
+    let x = lua.load("0.1").eval::<f16>()?;
+    assert!((x.to_f64() - 0.1).abs() < 1e-3);
+    let x = lua.load("2.71").eval::<bf16>()?;
+    assert!((x.to_f64() - 2.71).abs() < 1e-2);
+    let f = lua.create_function(|_, x: bf16| Ok(x))?;
+    assert_eq!(f.call::<bf16>(bf16::from_f32(-2.5))?, bf16::from_f32(-2.5));
+    assert_eq!(lua.load("'2'").eval::<f16>()?, f16::from_f32(2.0));
+
+    // Out-of-range finite numbers are rejected, infinities and NaN pass through
+    let err = lua.load("70000").eval::<f16>().unwrap_err();
+    assert!(err.to_string().contains("out of range"), "{err}");
+    assert!(lua.load("1e39").eval::<bf16>().is_err());
+    assert!(lua.load("1/0").eval::<f16>()?.is_infinite());
+    assert!(lua.load("0/0").eval::<bf16>()?.is_nan());
+    assert!(lua.load("'abc'").eval::<f16>().is_err());
+
+    Ok(())
+}
+
+#[cfg(feature = "rust_decimal")]
+#[test]
+fn test_rust_decimal() -> Result<()> {
+    use rust_decimal::Decimal;
+    use std::str::FromStr;
+
+    let lua = Lua::new();
+
+    // Decimals are passed as strings to keep every digit
+    let tenth = Decimal::from_str("0.1").unwrap();
+    lua.globals().set("d", tenth)?;
+    assert_eq!(lua.load("type(d)").eval::<String>()?, "string");
+    assert_eq!(lua.load("d").eval::<String>()?, "0.1");
+    assert_eq!(lua.globals().get::<Decimal>("d")?, tenth);
+
+    let precise = Decimal::from_str("12345678901234567.890123456789").unwrap();
+    let f = lua.create_function(|_, d: Decimal| Ok(d))?;
+    assert_eq!(f.call::<Decimal>(precise)?, precise);
+    assert_eq!(lua.load("'-0.000001'").eval::<Decimal>()?, Decimal::new(-1, 6));
+
+    // Only numbers holding exact integers are accepted
+    assert_eq!(lua.load("42").eval::<Decimal>()?, Decimal::from(42));
+    assert_eq!(lua.load("2.0").eval::<Decimal>()?, Decimal::from(2));
+    let err = lua.load("0.1").eval::<Decimal>().unwrap_err();
+    assert!(err.to_string().contains("not an exact integer"), "{err}");
+
+    let err = lua.load("'1.2.3'").eval::<Decimal>().unwrap_err();
+    assert!(err.to_string().contains("invalid decimal"), "{err}");
+    assert!(lua.load("{}").eval::<Decimal>().is_err());
+
+    Ok(())
+}
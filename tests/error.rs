@@ -99,3 +99,56 @@ fn test_error_anyhow() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_error_from_boxed() -> Result<()> {
+    #[derive(Debug)]
+    struct Outer(io::Error);
+
+    impl fmt::Display for Outer {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "failed to load config")
+        }
+    }
+
+    impl std::error::Error for Outer {
+        fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+            Some(&self.0)
+        }
+    }
+
+    fn load() -> std::result::Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        Err(Box::new(Outer(io::Error::new(
+            io::ErrorKind::NotFound,
+            "file not found",
+        ))))
+    }
+
+    let lua = Lua::new();
+
+    let func = lua.create_function(|_, ()| Ok(load()?))?;
+    lua.globals().set("func", func)?;
+    let msg = lua
+        .load("local _, err = pcall(func); return tostring(err)")
+        .eval::<String>()?;
+    assert!(msg.contains("failed to load config: file not found"), "{msg}");
+
+    let err = Error::from(load().unwrap_err());
+    assert_eq!(err.to_string(), "failed to load config: file not found");
+    assert!(err.downcast_ref::<Outer>().is_some());
+    // The sources are already part of the message
+    assert!(std::error::Error::source(&err).is_none());
+
+    // Errors without sources are wrapped as is
+    let err = Error::from(Box::<dyn std::error::Error + Send + Sync>::from("plain"));
+    assert_eq!(err.to_string(), "plain");
+
+    // `mlua::Error` is unwrapped
+    #[cfg(feature = "error-send")]
+    {
+        let err = Error::from(Box::new(Error::runtime("boom")) as Box<dyn std::error::Error + Send + Sync>);
+        assert!(matches!(err, Error::RuntimeError(ref msg) if msg == "boom"));
+    }
+
+    Ok(())
+}
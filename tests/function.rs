@@ -1,4 +1,4 @@
-use mlua::{Error, Function, Lua, Result, String, Table, Variadic};
+use mlua::{Error, Function, Lua, LuaFn, Result, String, Table, Variadic};
 
 #[test]
 fn test_function_call() -> Result<()> {
@@ -420,3 +420,35 @@ fn test_function_wrap_raw() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_lua_fn() -> Result<()> {
+    struct Handlers {
+        on_add: LuaFn,
+        on_greet: LuaFn,
+    }
+
+    let handlers = Handlers {
+        on_add: LuaFn::new(|_, (a, b): (i64, i64)| Ok(a + b)),
+        on_greet: LuaFn::new(|lua, name: String| lua.create_string(format!("hi {}", name.to_str()?))),
+    };
+
+    let lua = Lua::new();
+    let table = lua.create_table()?;
+    table.set("add", handlers.on_add)?;
+    table.set("greet", handlers.on_greet)?;
+    lua.globals().set("handlers", table)?;
+    lua.load(
+        r#"
+        assert(handlers.add(2, 3) == 5)
+        assert(handlers.greet("lua") == "hi lua")
+        assert(not pcall(handlers.add, "x", 1))
+    "#,
+    )
+    .exec()?;
+
+    let func = lua.pack(LuaFn::new(|_, ()| Ok("called")))?;
+    assert_eq!(func.as_function().unwrap().call::<String>(())?, "called");
+
+    Ok(())
+}
@@ -106,6 +106,13 @@ fn test_vectors() -> Result<()> {
     let v: [f64; 3] = lua.load("vector.create(1, 2, 3)").eval()?;
     assert!(v == [1.0, 2.0, 3.0]);
 
+    // Round trip via an array
+    let v = lua.pack(Vector::from([1.0, 2.0, 3.0]))?;
+    assert!(matches!(v, Value::Vector(_)));
+    assert_eq!(lua.unpack::<[f32; 3]>(v)?, [1.0, 2.0, 3.0]);
+    let arr: [f32; 3] = lua.load("vector.create(4, 5, 6)").eval::<Vector>()?.into();
+    assert_eq!(arr, [4.0, 5.0, 6.0]);
+
     // Test vector methods
     lua.load(
         r#"
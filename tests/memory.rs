@@ -67,6 +67,37 @@ fn test_memory_limit_thread() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_memory_limit_sequence() -> Result<()> {
+    let lua = Lua::new();
+
+    if cfg!(feature = "luajit") && lua.set_memory_limit(0).is_err() {
+        // seems this luajit version does not support memory limit
+        return Ok(());
+    }
+
+    lua.set_memory_limit(lua.used_memory() + 100_000)?;
+
+    let vec = (1..=1000).map(|i| i as f64).collect::<Vec<_>>();
+    let t = lua.create_sequence_from(vec.clone())?;
+    assert_eq!(t.raw_len(), 1000);
+    assert_eq!(t.sequence_values::<f64>().collect::<Result<Vec<_>>>()?, vec);
+    assert_eq!(lua.create_sequence_from(Vec::<f64>::new())?.raw_len(), 0);
+
+    // Preallocation fails
+    match lua.create_sequence_from(vec![0.0; 100_000]) {
+        Err(Error::MemoryError(_)) => {}
+        something_else => panic!("did not trigger memory error: {:?}", something_else),
+    };
+    // Iterator without a size hint grows the table while filling it
+    match lua.create_sequence_from((0..100_000).filter(|_| true)) {
+        Err(Error::MemoryError(_)) => {}
+        something_else => panic!("did not trigger memory error: {:?}", something_else),
+    };
+
+    Ok(())
+}
+
 #[test]
 fn test_gc_control() -> Result<()> {
     let lua = Lua::new();
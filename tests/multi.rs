@@ -1,4 +1,12 @@
-use mlua::{Error, ExternalError, Integer, IntoLuaMulti, Lua, MultiValue, Result, String, Value, Variadic};
+use std::collections::HashMap;
+use std::time::Duration;
+
+use mlua::{
+    Args, ArgsReader, Checked, Counted, ErrFirst, Error, ExternalError, Flatten, FromArgs, Function,
+    IgnoreRest, Integer, IntoLuaMulti, KvPairs, Lua, MultiValue, MultiValueBuilder, NamedTuple,
+    NonEmptyVariadic, Present, RaiseOnErr, RestArgs, Result, SplitDuration, SpreadKv, SpreadOptional, String,
+    Table, TableTuple, Unpacked, Value, Variadic,
+};
 
 #[test]
 fn test_result_conversions() -> Result<()> {
@@ -59,6 +67,74 @@ fn test_result_conversions() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_err_first() -> Result<()> {
+    let lua = Lua::new();
+
+    // Default: `(value)` or `(nil, err)`
+    let multi = Ok::<_, &str>(1).into_lua_multi(&lua)?;
+    assert_eq!(multi.len(), 1);
+    assert_eq!(multi[0], Value::Integer(1));
+
+    // Error first: `(nil, value)` or `(err, nil)`
+    let multi = ErrFirst(Ok::<_, &str>(1)).into_lua_multi(&lua)?;
+    assert_eq!(multi.len(), 2);
+    assert_eq!(multi[0], Value::Nil);
+    assert_eq!(multi[1], Value::Integer(1));
+    let multi = ErrFirst(Err::<i32, _>("failure")).into_lua_multi(&lua)?;
+    assert_eq!(multi.len(), 2);
+    assert_eq!(multi[0].as_str().unwrap(), "failure");
+    assert_eq!(multi[1], Value::Nil);
+
+    let div = lua.create_function(|_, (a, b): (i64, i64)| {
+        Ok(ErrFirst::from(a.checked_div(b).ok_or("division by zero")))
+    })?;
+    lua.globals().set("div", div)?;
+    lua.load(
+        r#"
+        local err, r = div(6, 3)
+        assert(err == nil and r == 2)
+        local err, r = div(1, 0)
+        assert(err == "division by zero" and r == nil)
+    "#,
+    )
+    .exec()?;
+
+    Ok(())
+}
+
+#[test]
+fn test_raise_on_err() -> Result<()> {
+    let lua = Lua::new();
+
+    let multi = RaiseOnErr(Ok::<_, &str>((1, 2))).into_lua_multi(&lua)?;
+    assert_eq!(multi.len(), 2);
+    assert!(RaiseOnErr(Err::<i32, _>("failure")).into_lua_multi(&lua).is_err());
+
+    let div = lua.create_function(|_, (a, b): (i64, i64)| Ok(a.checked_div(b).ok_or("division by zero")))?;
+    let div_raise = lua.create_function(|_, (a, b): (i64, i64)| {
+        Ok(RaiseOnErr(a.checked_div(b).ok_or("division by zero")))
+    })?;
+    lua.globals().set("div", div)?;
+    lua.globals().set("div_raise", div_raise)?;
+    lua.load(
+        r#"
+        assert(div_raise(6, 3) == 2)
+
+        -- `nil, err` idiom does not raise
+        local ok, r, err = pcall(div, 1, 0)
+        assert(ok and r == nil and err == "division by zero")
+
+        -- The error unwinds and is caught by `pcall`
+        local ok, err = pcall(div_raise, 1, 0)
+        assert(not ok and tostring(err):find("division by zero"))
+    "#,
+    )
+    .exec()?;
+
+    Ok(())
+}
+
 #[test]
 fn test_multivalue() {
     let mut multi = MultiValue::with_capacity(3);
@@ -72,6 +148,99 @@ fn test_multivalue() {
     let _multi2 = MultiValue::from_vec(vec);
 }
 
+#[test]
+fn test_multivalue_into_typed() -> Result<()> {
+    let lua = Lua::new();
+
+    let values = lua.load("return 1, 2.0, '3'").eval::<MultiValue>()?;
+    assert_eq!(values.clone().into_vec().len(), 3);
+    assert_eq!(values.into_typed::<i64>(&lua)?, vec![1, 2, 3]);
+
+    let values = lua.load("return 1, 'x', 3").eval::<MultiValue>()?;
+    let err = values.into_typed::<i64>(&lua).unwrap_err();
+    assert!(matches!(err, Error::WithContext { ref context, .. } if context == "value #2"));
+
+    assert!(MultiValue::new().into_typed::<i64>(&lua)?.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn test_tuple_from_lua_error_position() -> Result<()> {
+    let lua = Lua::new();
+
+    let f = lua.load("function() return 1, {}, true end").eval::<Function>()?;
+    let err = f.call::<(i64, std::string::String, bool)>(()).unwrap_err();
+    assert!(matches!(err, Error::WithContext { ref context, .. } if context == "value #2"));
+    assert!(err.to_string().contains("value #2"), "{err}");
+
+    // The last element is reported as well
+    let err = f.call::<(i64, Option<Table>, i64)>(()).unwrap_err();
+    assert!(err.to_string().contains("value #3"), "{err}");
+
+    // Same for conversions from `MultiValue`
+    let values = f.call::<MultiValue>(())?;
+    let err = lua.unpack_multi::<(i64, i64)>(values).unwrap_err();
+    assert!(err.to_string().contains("value #2"), "{err}");
+
+    Ok(())
+}
+
+#[test]
+fn test_multivalue_builder() -> Result<()> {
+    let lua = Lua::new();
+
+    let f = lua
+        .load("function(...) return select('#', ...), ... end")
+        .eval::<Function>()?;
+    let args = MultiValueBuilder::with_capacity(&lua, 3)
+        .push_back(2)
+        .push_back("three")
+        .push_front(true)
+        .build()?;
+    assert_eq!(args.len(), 3);
+    let (n, a, b, c) = f.call::<(i64, bool, i64, std::string::String)>(args)?;
+    assert_eq!((n, a, b, c.as_str()), (3, true, 2, "three"));
+
+    // The builder can be passed directly
+    let n = f.call::<i64>(MultiValueBuilder::new(&lua).push_back(Value::Nil).push_back(1))?;
+    assert_eq!(n, 2);
+    assert!(MultiValueBuilder::new(&lua).build()?.is_empty());
+
+    // The first error is reported by `build`
+    let other = Lua::new();
+    let key = other.create_registry_value(1)?;
+    let err = MultiValueBuilder::new(&lua)
+        .push_back(1)
+        .push_front(&key)
+        .push_back(2)
+        .build()
+        .unwrap_err();
+    assert!(matches!(err, Error::MismatchedRegistryKey), "{err:?}");
+
+    Ok(())
+}
+
+#[test]
+fn test_multivalue_get_typed() -> Result<()> {
+    let lua = Lua::new();
+
+    let f = lua
+        .load("function(...) return 'a', true, 42, 'd' end")
+        .eval::<Function>()?;
+    let values = f.call::<MultiValue>(())?;
+    assert_eq!(values.get_typed::<i64>(2, &lua)?, 42);
+    assert_eq!(values.get_typed::<std::string::String>(0, &lua)?, "a");
+    assert_eq!(values.len(), 4);
+
+    // Reading past the end converts `nil`
+    assert_eq!(values.get_typed::<Option<i64>>(10, &lua)?, None);
+    let err = values.get_typed::<i64>(4, &lua).unwrap_err();
+    assert!(matches!(err, Error::WithContext { ref context, .. } if context == "value #5"));
+
+    Ok(())
+}
+
 #[test]
 fn test_variadic() {
     let mut var = Variadic::with_capacity(3);
@@ -82,4 +251,531 @@ fn test_variadic() {
     assert_eq!(&vec, &[1, 2, 3]);
     let var2 = Variadic::from(vec);
     assert_eq!(var2.as_slice(), &[1, 2, 3]);
+
+    let var3 = Variadic::from_vec(vec![4, 5]);
+    assert_eq!(var3.len(), 2);
+    assert_eq!(var3.into_inner(), vec![4, 5]);
+    assert!(Variadic::<i32>::from_vec(Vec::new()).into_inner().is_empty());
+}
+
+#[test]
+fn test_non_empty_variadic() -> Result<()> {
+    let lua = Lua::new();
+
+    let join = lua.create_function(|_, (sep, parts): (std::string::String, NonEmptyVariadic<i64>)| {
+        assert_eq!(*parts.first(), parts[0]);
+        Ok(parts.iter().map(|p| p.to_string()).collect::<Vec<_>>().join(&sep))
+    })?;
+    assert_eq!(join.call::<std::string::String>(("-", 1))?, "1");
+    assert_eq!(join.call::<std::string::String>(("-", 1, 2, 3))?, "1-2-3");
+
+    // No trailing values
+    match join.call::<()>("-") {
+        Err(Error::CallbackError { cause, .. }) => match cause.as_ref() {
+            Error::BadArgument { pos, cause, .. } => {
+                assert_eq!(*pos, 2);
+                assert!(cause.to_string().contains("expected at least 1 argument"));
+            }
+            err => panic!("expected BadArgument, got {err:?}"),
+        },
+        r => panic!("expected CallbackError, got {r:?}"),
+    }
+
+    // Bad value position is reported
+    match join.call::<()>(("-", 1, "x")) {
+        Err(Error::CallbackError { cause, .. }) => match cause.as_ref() {
+            Error::BadArgument { pos, .. } => assert_eq!(*pos, 3),
+            err => panic!("expected BadArgument, got {err:?}"),
+        },
+        r => panic!("expected CallbackError, got {r:?}"),
+    }
+
+    assert!(lua
+        .unpack_multi::<NonEmptyVariadic<i64>>(MultiValue::new())
+        .is_err());
+    let vals = lua.unpack_multi::<NonEmptyVariadic<i64>>(lua.pack_multi((1, 2))?)?;
+    assert_eq!(vals.into_vec(), vec![1, 2]);
+
+    // Returning from Rust
+    let f = lua.create_function(|_, ()| Ok(NonEmptyVariadic::new(1, vec![2, 3])))?;
+    assert_eq!(f.call::<(i64, i64, i64)>(())?, (1, 2, 3));
+    let vals = NonEmptyVariadic::try_from(vec!["a", "b"]).unwrap();
+    assert_eq!(vals.into_lua_multi(&lua)?.len(), 2);
+    assert!(NonEmptyVariadic::<i64>::try_from(Vec::new()).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_rest_args() -> Result<()> {
+    let lua = Lua::new();
+
+    let f = lua.create_function(|_, (first, rest): (i64, RestArgs<i64>)| {
+        assert_eq!(rest.len(), 3);
+        let rest = rest.collect::<Result<Vec<_>>>()?;
+        Ok(format!("{first}:{rest:?}"))
+    })?;
+    assert_eq!(f.call::<String>((0, 1, 2, 3))?, "0:[1, 2, 3]");
+
+    // Values are converted lazily, so the error is reported only for the bad one
+    let f = lua.create_function(|_, args: RestArgs<i64>| {
+        let results = args.map(|r| r.is_ok()).collect::<Vec<_>>();
+        Ok(Variadic::from_iter(results))
+    })?;
+    let (a, b, c) = f.call::<(bool, bool, bool)>((1, "x", 3))?;
+    assert!(a && !b && c);
+
+    let f = lua.create_function(|_, args: RestArgs| Ok(args.is_empty()))?;
+    assert!(f.call::<bool>(())?);
+
+    let args = lua.unpack_multi::<RestArgs>(MultiValue::from_vec(vec![Value::Integer(1)]))?;
+    assert_eq!(args.into_values().len(), 1);
+
+    // Conversion errors point at the argument position
+    let f = lua.create_function(|_, (_, rest): (i64, RestArgs<i64>)| rest.sum::<Result<i64>>())?;
+    let err = f.call::<i64>((1, 2, "x")).unwrap_err();
+    assert!(err.to_string().contains("bad argument #3"), "{err}");
+
+    // Iterating after the Lua instance is destroyed is an error, not a panic
+    let mut args = lua.load("return 1, 2").eval::<RestArgs<i64>>()?;
+    drop(lua);
+    let err = args.next().unwrap().unwrap_err();
+    assert!(err.to_string().contains("Lua instance is destroyed"), "{err}");
+
+    Ok(())
+}
+
+#[test]
+fn test_unpacked() -> Result<()> {
+    let lua = Lua::new();
+
+    let f = lua.create_function(|_, ()| Ok(Unpacked([1.5, 2.5, 3.5])))?;
+    lua.globals().set("f", f.clone())?;
+    lua.load(
+        r#"
+        local a, b, c = f()
+        assert(a == 1.5 and b == 2.5 and c == 3.5)
+        assert(select('#', f()) == 3)
+    "#,
+    )
+    .exec()?;
+    assert_eq!(f.call::<(f64, f64, f64)>(())?, (1.5, 2.5, 3.5));
+
+    let multi = Unpacked(["a", "b"]).into_lua_multi(&lua)?;
+    assert_eq!(multi.len(), 2);
+    assert_eq!(Unpacked::<[i32; 0]>([]).into_lua_multi(&lua)?.len(), 0);
+
+    // Runtime-length lists are spread using `Variadic`
+    let range = lua.create_function(|_, n: i64| Ok((1..=n).collect::<Variadic<_>>()))?;
+    lua.globals().set("range", range)?;
+    lua.load(
+        r#"
+        local a, b, c = range(3)
+        assert(a == 1 and b == 2 and c == 3)
+        assert(select('#', range(0)) == 0)
+        assert(select('#', range(10000)) == 10000)
+    "#,
+    )
+    .exec()?;
+    let values = vec![Value::Boolean(true), Value::Nil, Value::Integer(7)];
+    assert_eq!(Variadic::from(values).into_lua_multi(&lua)?.len(), 3);
+
+    Ok(())
+}
+
+#[test]
+fn test_flatten() -> Result<()> {
+    let lua = Lua::new();
+
+    let f = lua.create_function(|lua, ()| {
+        let rest = MultiValue::from_vec(vec![Value::Integer(2), lua.pack("three")?]);
+        Ok(("one", Flatten(rest), 4))
+    })?;
+    lua.globals().set("f", f.clone())?;
+    lua.load(
+        r#"
+        local a, b, c, d = f()
+        assert(a == "one" and b == 2 and c == "three" and d == 4)
+        assert(select('#', f()) == 4)
+    "#,
+    )
+    .exec()?;
+
+    let multi = (1, Flatten(Variadic::from(vec![2, 3])), 4).into_lua_multi(&lua)?;
+    assert_eq!(multi.into_typed::<i64>(&lua)?, vec![1, 2, 3, 4]);
+
+    // Empty and large flattened values
+    let multi = (Flatten(()), 1, Flatten(Variadic::from(vec![0; 10000])), 2).into_lua_multi(&lua)?;
+    assert_eq!(multi.len(), 10002);
+    let g = lua.create_function(|_, ()| Ok((Flatten(()), 1, Flatten(Variadic::from(vec![0; 10000])), 2)))?;
+    lua.globals().set("g", g)?;
+    lua.load("assert(select('#', g()) == 10002 and select(10002, g()) == 2)")
+        .exec()?;
+
+    Ok(())
+}
+
+#[test]
+fn test_table_tuple() -> Result<()> {
+    let lua = Lua::new();
+
+    let TableTuple((n, s, b)) = lua
+        .load("{1, 'x', true}")
+        .eval::<TableTuple<(i64, String, bool)>>()?;
+    assert_eq!((n, s.to_str()?.as_ref(), b), (1, "x", true));
+
+    // Extra elements are ignored
+    let TableTuple((a,)) = lua.load("{1, 2}").eval::<TableTuple<(i64,)>>()?;
+    assert_eq!(a, 1);
+
+    let err = lua
+        .load("{1, 'x'}")
+        .eval::<TableTuple<(i64, String, bool)>>()
+        .unwrap_err();
+    assert!(
+        err.to_string().contains("expected table of length 3, got 2"),
+        "{err}"
+    );
+    assert!(lua.load("1").eval::<TableTuple<(i64,)>>().is_err());
+
+    let t = lua.pack(TableTuple((1, "a", false)))?;
+    assert_eq!(t.as_table().unwrap().raw_len(), 3);
+    let t = lua.pack(TableTuple((1, Value::Nil, 3)))?;
+    assert_eq!(t.as_table().unwrap().raw_get::<i64>(3)?, 3);
+
+    Ok(())
+}
+
+#[test]
+fn test_spread_kv() -> Result<()> {
+    let lua = Lua::new();
+
+    let f = lua.create_function(|_, ()| {
+        let map = HashMap::from([("a".to_string(), 1), ("b".to_string(), 2)]);
+        Ok(SpreadKv(map))
+    })?;
+    lua.globals().set("f", &f)?;
+    lua.load(
+        r##"
+        assert(select("#", f()) == 4)
+        local k1, v1, k2, v2 = f()
+        local t = {[k1] = v1, [k2] = v2}
+        assert(t.a == 1 and t.b == 2)
+    "##,
+    )
+    .exec()?;
+
+    // Read it back
+    let KvPairs(map) = f.call::<KvPairs<std::string::String, i64>>(())?;
+    assert_eq!(map, HashMap::from([("a".to_string(), 1), ("b".to_string(), 2)]));
+
+    // Odd number of values pairs the last key with nil
+    let g =
+        lua.create_function(|_, KvPairs(map): KvPairs<std::string::String, Option<i64>>| Ok(map.len()))?;
+    assert_eq!(g.call::<usize>(("x", 1, "y"))?, 2);
+
+    Ok(())
+}
+
+#[test]
+fn test_checked_args() -> Result<()> {
+    let lua = Lua::new();
+
+    let f = lua.create_function(
+        |_, Checked((a, s, b)): Checked<(i64, std::string::String, i64)>| Ok(a + b + s.len() as i64),
+    )?;
+    assert_eq!(f.call::<i64>((1, "abc", 2))?, 6);
+
+    // Both wrong arguments are reported
+    let err = f.call::<i64>(("x", "abc", "y")).unwrap_err();
+    let err = err.to_string();
+    assert!(
+        err.contains("bad argument #1: error converting Lua string to i64"),
+        "{err}"
+    );
+    assert!(
+        err.contains("bad argument #3: error converting Lua string to i64"),
+        "{err}"
+    );
+    assert!(!err.contains("bad argument #2"), "{err}");
+
+    // Direct conversion
+    let args = MultiValue::from_vec(vec![Value::Boolean(false), Value::Boolean(true)]);
+    let res = lua.unpack_multi::<Checked<(i64, std::string::String)>>(args);
+    assert!(res.is_err_and(|err| {
+        let err = err.to_string();
+        err.contains("bad argument #1") && err.contains("bad argument #2")
+    }));
+
+    Ok(())
+}
+
+#[test]
+fn test_counted() -> Result<()> {
+    let lua = Lua::new();
+
+    let sum = lua.create_function(|_, Counted(vals): Counted<i64>| Ok(vals.iter().sum::<i64>()))?;
+    assert_eq!(sum.call::<i64>((3, 1, 2, 3))?, 6);
+    assert_eq!(sum.call::<i64>(0)?, 0);
+    // Values after the counted ones are ignored
+    assert_eq!(sum.call::<i64>((2, 1, 2, 100))?, 3);
+
+    // Not enough values
+    let err = sum.call::<i64>((3, 1, 2)).unwrap_err();
+    assert!(err.to_string().contains("expected 3 values, got 2"), "{err}");
+
+    // Missing or invalid count
+    assert!(sum.call::<i64>(()).is_err());
+    assert!(sum.call::<i64>(-1).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_split_duration() -> Result<()> {
+    let lua = Lua::new();
+
+    let f = lua.create_function(|_, ()| Ok(SplitDuration(Duration::new(5, 250_000_000))))?;
+    lua.globals().set("f", &f)?;
+    lua.load(
+        r##"
+        assert(select("#", f()) == 2)
+        local secs, nanos = f()
+        assert(secs == 5 and nanos == 250000000)
+    "##,
+    )
+    .exec()?;
+
+    assert_eq!(f.call::<(i64, u32)>(())?, (5, 250_000_000));
+
+    Ok(())
+}
+
+#[test]
+fn test_spread_optional() -> Result<()> {
+    let lua = Lua::new();
+
+    let f = lua.create_function(|_, b: Option<std::string::String>| Ok(SpreadOptional((1, b, true))))?;
+    lua.globals().set("f", &f)?;
+    lua.load(
+        r##"
+        -- Some middle value
+        assert(select("#", f("x")) == 3)
+        local a, b, c = f("x")
+        assert(a == 1 and b == "x" and c == true)
+
+        -- None middle value is omitted and the last value is shifted forward
+        assert(select("#", f(nil)) == 2)
+        local a, b, c = f(nil)
+        assert(a == 1 and b == true and c == nil)
+    "##,
+    )
+    .exec()?;
+
+    // Compare with the default tuple conversion
+    let multi = (1, None::<i32>, true).into_lua_multi(&lua)?;
+    assert_eq!(multi.len(), 3);
+    let multi = SpreadOptional((1, None::<i32>, true)).into_lua_multi(&lua)?;
+    assert_eq!(multi.len(), 2);
+    assert_eq!(multi[1], Value::Boolean(true));
+
+    Ok(())
+}
+
+#[test]
+fn test_named_tuple() -> Result<()> {
+    let lua = Lua::new();
+
+    let t = lua.load("{ x = 1, y = 2, z = 3 }").eval::<Table>()?;
+    let NamedTuple([y, x]) = NamedTuple::from_table(&t, ["y", "x"])?;
+    assert_eq!((x, y), (Value::Integer(1), Value::Integer(2)));
+
+    // Missing field
+    let t = lua.load("{ x = 1 }").eval::<Table>()?;
+    let err = NamedTuple::from_table(&t, ["x", "y"]).unwrap_err();
+    assert!(err.to_string().contains("missing field `y`"), "{err}");
+
+    // From function arguments
+    let f = lua.create_function(|lua, args: MultiValue| {
+        let NamedTuple([x, y]) = NamedTuple::from_args(args, ["x", "y"])?;
+        Ok(lua.unpack::<i64>(x)? * lua.unpack::<i64>(y)?)
+    })?;
+    lua.globals().set("f", f)?;
+    assert_eq!(lua.load("f{ x = 3, y = 4 }").eval::<i64>()?, 12);
+    assert!(lua.load("f(1, 2)").exec().is_err());
+
+    Ok(())
+}
+
+#[cfg(feature = "tuple-24")]
+#[test]
+fn test_tuple_24() -> Result<()> {
+    #[rustfmt::skip]
+    type I20 = (
+        i32, i32, i32, i32, i32, i32, i32, i32, i32, i32,
+        i32, i32, i32, i32, i32, i32, i32, i32, i32, i32,
+    );
+
+    let lua = Lua::new();
+
+    let values: I20 = (
+        1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20,
+    );
+    let multi = values.into_lua_multi(&lua)?;
+    assert_eq!(multi.len(), 20);
+    assert_eq!(multi[19], Value::Integer(20));
+
+    let reverse = lua
+        .load(
+            r#"
+            function(...)
+                local unpack = table.unpack or unpack
+                local t, r = {...}, {}
+                for i = #t, 1, -1 do r[#r + 1] = t[i] end
+                return unpack(r)
+            end
+        "#,
+        )
+        .eval::<mlua::Function>()?;
+    let (a, b, c, d, e, f, g, h, i, j, k, l, m, n, o, p, q, r, s, t) = reverse.call::<I20>(values)?;
+    assert_eq!(
+        [a, b, c, d, e, f, g, h, i, j],
+        [20, 19, 18, 17, 16, 15, 14, 13, 12, 11]
+    );
+    assert_eq!([k, l, m, n, o, p, q, r, s, t], [10, 9, 8, 7, 6, 5, 4, 3, 2, 1]);
+
+    let checked = lua.create_function(|_, Checked(values): Checked<I20>| Ok(values.19))?;
+    assert_eq!(checked.call::<i32>(values)?, 20);
+    let err = checked.call::<i32>((1, "x")).unwrap_err().to_string();
+    assert!(err.contains("bad argument #2"), "{err}");
+    assert!(err.contains("bad argument #20"), "{err}");
+
+    #[rustfmt::skip]
+    let optional = (
+        1, None::<i32>, 3, 4, 5, 6, 7, 8, 9, 10,
+        11, 12, 13, 14, 15, 16, 17, 18, 19, Some(20),
+    );
+    let spread = SpreadOptional(optional).into_lua_multi(&lua)?;
+    assert_eq!(spread.len(), 19);
+    assert_eq!(spread[1], Value::Integer(3));
+    assert_eq!(spread[18], Value::Integer(20));
+
+    Ok(())
+}
+
+#[test]
+fn test_from_args() -> Result<()> {
+    #[derive(Debug)]
+    struct Spawn {
+        name: std::string::String,
+        count: usize,
+        delay: Option<f64>,
+    }
+
+    impl FromArgs for Spawn {
+        fn from_args(args: &mut ArgsReader) -> Result<Self> {
+            Ok(Spawn {
+                name: args.next("name")?,
+                count: args.next("count")?,
+                delay: args.next("delay")?,
+            })
+        }
+    }
+
+    let lua = Lua::new();
+
+    let spawn =
+        lua.create_function(|_, Args(s): Args<Spawn>| Ok(format!("{}x{}/{:?}", s.name, s.count, s.delay)))?;
+    assert_eq!(spawn.call::<std::string::String>(("orc", 3))?, "orcx3/None");
+    assert_eq!(
+        spawn.call::<std::string::String>(("orc", 3, 0.5))?,
+        "orcx3/Some(0.5)"
+    );
+
+    match spawn.call::<()>(("orc", "many")) {
+        Err(Error::CallbackError { ref cause, .. }) => match cause.as_ref() {
+            Error::BadArgument { pos, name, .. } => {
+                assert_eq!(*pos, 2);
+                assert_eq!(name.as_deref(), Some("count"));
+            }
+            err => panic!("expected BadArgument, got {err:?}"),
+        },
+        r => panic!("expected CallbackError, got {r:?}"),
+    }
+    let err = spawn.call::<()>(("orc", "many")).unwrap_err();
+    assert!(err.to_string().contains("bad argument `count`"), "{err}");
+
+    // Positions are shifted by preceding arguments
+    let f = lua.create_function(|_, (_, Args(s)): (i64, Args<Spawn>)| Ok(s.count))?;
+    match f.call::<()>((1, "orc", 1, "soon")) {
+        Err(Error::CallbackError { ref cause, .. }) => {
+            assert!(matches!(cause.as_ref(), Error::BadArgument { pos: 4, .. }))
+        }
+        r => panic!("expected CallbackError, got {r:?}"),
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_ignore_rest() -> Result<()> {
+    let lua = Lua::new();
+
+    let f = lua.create_function(|_, (n, IgnoreRest): (i64, IgnoreRest)| Ok(n * 2))?;
+    lua.globals().set("f", f.clone())?;
+    lua.load(
+        r#"
+        assert(f(21, "unused", {}) == 42)
+        assert(f(1) == 2)
+    "#,
+    )
+    .exec()?;
+    assert_eq!(f.call::<i64>((5, true, "x"))?, 10);
+
+    // Only the leading arguments are checked
+    assert!(f.call::<i64>(("x", 1, 2)).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_present() -> Result<()> {
+    let lua = Lua::new();
+
+    let f = lua.create_function(|_, (a, Present(b)): (i64, Present<Option<i64>>)| {
+        Ok(match b {
+            None => format!("{a}: missing"),
+            Some(None) => format!("{a}: nil"),
+            Some(Some(b)) => format!("{a}: {b}"),
+        })
+    })?;
+    lua.globals().set("f", f.clone())?;
+    lua.load(
+        r#"
+        assert(f(1) == "1: missing")
+        assert(f(1, nil) == "1: nil")
+        assert(f(1, 2) == "1: 2")
+        assert(f(1, 2, 3) == "1: 2")
+        assert(select('#', f(1, nil)) == 1)
+    "#,
+    )
+    .exec()?;
+    assert_eq!(f.call::<std::string::String>(1)?, "1: missing");
+    assert_eq!(f.call::<std::string::String>((1, Value::Nil))?, "1: nil");
+
+    // Conversion errors keep the argument position
+    match f.call::<()>((1, "x")) {
+        Err(Error::CallbackError { ref cause, .. }) => {
+            assert!(matches!(cause.as_ref(), Error::BadArgument { pos: 2, .. }))
+        }
+        r => panic!("expected CallbackError, got {r:?}"),
+    }
+
+    // Outside of callbacks
+    let Present(v) = lua.unpack_multi::<Present<i64>>(MultiValue::new())?;
+    assert_eq!(v, None);
+    let Present(v) = lua.unpack_multi::<Present<Option<i64>>>(lua.pack_multi(Value::Nil)?)?;
+    assert_eq!(v, Some(None));
+
+    Ok(())
 }
@@ -5,8 +5,8 @@ use std::error::Error as StdError;
 
 use bstr::BString;
 use mlua::{
-    AnyUserData, DeserializeOptions, Error, ExternalResult, IntoLua, Lua, LuaSerdeExt, Result as LuaResult,
-    SerializeOptions, UserData, Value,
+    AnyUserData, DeserializeOptions, Deserialized, Error, ExternalResult, IntoLua, Lua, LuaSerdeExt,
+    Result as LuaResult, SerializeOptions, Serialized, UserData, Value,
 };
 use serde::{Deserialize, Serialize};
 
@@ -721,6 +721,62 @@ fn test_arbitrary_precision() {
     );
 }
 
+#[test]
+fn test_serialized_deserialized_wrappers() -> LuaResult<()> {
+    #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+    struct Inner {
+        tags: Vec<String>,
+        weight: f64,
+    }
+
+    #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+    struct Outer {
+        id: u32,
+        inner: Inner,
+        extra: Option<HashMap<String, i32>>,
+    }
+
+    let lua = Lua::new();
+
+    let outer = Outer {
+        id: 7,
+        inner: Inner {
+            tags: vec!["a".into(), "b".into()],
+            weight: 1.5,
+        },
+        extra: Some(HashMap::from([("k".to_string(), 1)])),
+    };
+
+    lua.globals().set("outer", Serialized(outer.clone()))?;
+    lua.load(
+        r#"
+        assert(outer.id == 7)
+        assert(outer.inner.tags[1] == "a" and outer.inner.tags[2] == "b")
+        assert(outer.inner.weight == 1.5)
+        assert(outer.extra.k == 1)
+    "#,
+    )
+    .exec()?;
+
+    let Deserialized(outer2) = lua.globals().get::<Deserialized<Outer>>("outer")?;
+    assert_eq!(outer2, outer);
+
+    // Works as function arguments and return values
+    let f = lua.create_function(|_, Deserialized(mut outer): Deserialized<Outer>| {
+        outer.id += 1;
+        Ok(Serialized(outer))
+    })?;
+    let Deserialized(outer3) = f.call::<Deserialized<Outer>>(Serialized(outer.clone()))?;
+    assert_eq!(outer3.id, 8);
+    assert_eq!(outer3.inner, outer.inner);
+
+    // Deserialization errors are propagated
+    let res = lua.load("{id = 'x'}").eval::<Deserialized<Outer>>();
+    assert!(matches!(res, Err(Error::DeserializeError(_))));
+
+    Ok(())
+}
+
 #[cfg(feature = "luau")]
 #[test]
 fn test_buffer_serialize() -> LuaResult<()> {
@@ -1,4 +1,4 @@
-use mlua::{Error, Lua, ObjectLike, Result, Table, Value};
+use mlua::{Error, Index, Lua, ObjectLike, Result, Table, TableBuilder, Value};
 
 #[test]
 fn test_globals_set_get() -> Result<()> {
@@ -93,7 +93,7 @@ fn test_table_push_pop() -> Result<()> {
     assert_eq!(table2.len()?, 2);
     assert_eq!(
         table2.sequence_values::<i64>().collect::<Result<Vec<_>>>()?,
-        vec![]
+        Vec::<i64>::new()
     );
     assert_eq!(table2.pop::<i64>()?, 345);
     assert_eq!(table2.pop::<i64>()?, 234);
@@ -196,6 +196,54 @@ fn test_table_sequence_from() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_table_builder() -> Result<()> {
+    let lua = Lua::new();
+
+    let built = TableBuilder::new(&lua)
+        .set("name", "server")
+        .set("limits", TableBuilder::new(&lua).set("cpu", 2).set("mem", 512))
+        .set("ports", TableBuilder::new(&lua).seq([80, 443]))
+        .seq(["a", "b"])
+        .build()?;
+
+    let manual = lua.create_table()?;
+    manual.set("name", "server")?;
+    let limits = lua.create_table()?;
+    limits.set("cpu", 2)?;
+    limits.set("mem", 512)?;
+    manual.set("limits", limits)?;
+    manual.set("ports", lua.create_sequence_from([80, 443])?)?;
+    manual.push("a")?;
+    manual.push("b")?;
+
+    let same = lua
+        .load(
+            r#"
+            local function deep_eq(a, b)
+                if type(a) ~= "table" or type(b) ~= "table" then return a == b end
+                for k, v in pairs(a) do if not deep_eq(v, b[k]) then return false end end
+                for k in pairs(b) do if a[k] == nil then return false end end
+                return true
+            end
+            return deep_eq(...)
+        "#,
+        )
+        .call::<bool>((&built, &manual))?;
+    assert!(same);
+    assert_eq!(built.raw_len(), 2);
+    assert_eq!(built.get::<Table>("ports")?, [80, 443]);
+
+    // Errors are deferred until `build`
+    let res = TableBuilder::new(&lua)
+        .set("a", Index(usize::MAX))
+        .set("b", 1)
+        .build();
+    assert!(res.is_err());
+
+    Ok(())
+}
+
 #[test]
 fn test_table_pairs() -> Result<()> {
     let lua = Lua::new();
@@ -6,8 +6,8 @@ use std::sync::Arc;
 use std::sync::atomic::{AtomicI64, Ordering};
 
 use mlua::{
-    AnyUserData, Error, ExternalError, Function, Lua, MetaMethod, Nil, ObjectLike, Result, String, UserData,
-    UserDataFields, UserDataMethods, UserDataRef, Value, Variadic,
+    AnyUserData, Error, ExternalError, Function, Lua, MetaMethod, Nil, ObjectLike, Result, Sequence, String,
+    UserData, UserDataFields, UserDataMethods, UserDataRef, Value, Variadic,
 };
 
 #[test]
@@ -1066,3 +1066,37 @@ fn test_userdata_wrappers() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_userdata_sequence() -> Result<()> {
+    struct Squares(i64);
+
+    impl UserData for Squares {
+        fn add_methods<M: UserDataMethods<Self>>(methods: &mut M) {
+            methods.add_meta_method(MetaMethod::Len, |_, this, ()| Ok(this.0));
+            methods.add_meta_method(MetaMethod::Index, |_, this, i: i64| {
+                Ok((1..=this.0).contains(&i).then_some(i * i))
+            });
+        }
+    }
+
+    struct NoLen;
+    impl UserData for NoLen {}
+
+    let lua = Lua::new();
+
+    let Sequence(v) = lua.convert::<Sequence<i64>>(Squares(4))?;
+    assert_eq!(v, vec![1, 4, 9, 16]);
+    let Sequence(v) = lua.convert::<Sequence<i64>>(Squares(0))?;
+    assert!(v.is_empty());
+
+    // Tables are read as usual
+    let Sequence(v) = lua.load("{1, 2, 3}").eval::<Sequence<i64>>()?;
+    assert_eq!(v, vec![1, 2, 3]);
+
+    // Userdata without `__len` is rejected
+    let res = lua.convert::<Sequence<i64>>(NoLen);
+    assert!(res.is_err_and(|err| err.to_string().contains("does not implement `__len`")));
+
+    Ok(())
+}
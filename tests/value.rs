@@ -3,7 +3,9 @@ use std::os::raw::c_void;
 use std::ptr;
 use std::string::String as StdString;
 
-use mlua::{Error, LightUserData, Lua, MultiValue, Result, UserData, UserDataMethods, Value};
+use mlua::{
+    Error, LightUserData, Lua, MultiValue, PlainValue, Result, TableKind, UserData, UserDataMethods, Value,
+};
 
 #[test]
 fn test_value_eq() -> Result<()> {
@@ -297,6 +299,32 @@ fn test_value_conversions() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_value_table_kind() -> Result<()> {
+    let lua = Lua::new();
+
+    let kind = |code: &str| lua.load(code).eval::<Value>()?.table_kind();
+
+    assert_eq!(kind("{}")?, TableKind::Empty);
+    assert_eq!(kind("{1, 2, 3}")?, TableKind::Array);
+    assert_eq!(kind("{a = 1, b = 2}")?, TableKind::Map);
+    assert_eq!(kind("{1, 2, a = 3}")?, TableKind::Mixed);
+
+    // Metatables are ignored
+    assert_eq!(
+        kind("setmetatable({}, {__len = function() return 5 end})")?,
+        TableKind::Empty
+    );
+
+    // Non-table values
+    assert!(matches!(
+        Value::Integer(1).table_kind(),
+        Err(Error::FromLuaConversionError { from: "integer", .. })
+    ));
+
+    Ok(())
+}
+
 #[test]
 fn test_value_exhaustive_match() {
     match Value::Nil {
@@ -318,3 +346,101 @@ fn test_value_exhaustive_match() {
         Value::Other(_) => {}
     }
 }
+
+#[test]
+fn test_value_to_plain() -> Result<()> {
+    let lua = Lua::new();
+
+    let value = lua
+        .load(r#"{ name = "test", list = { 1, 2.5, true }, nested = { empty = {} } }"#)
+        .eval::<Value>()?;
+    let plain = value.to_plain()?;
+
+    let PlainValue::Map(pairs) = &plain else {
+        panic!("expected map, got {plain:?}");
+    };
+    let get = |key: &str| {
+        pairs
+            .iter()
+            .find(|(k, _)| *k == PlainValue::String(key.into()))
+            .map(|(_, v)| v.clone())
+    };
+    assert_eq!(get("name"), Some(PlainValue::String(b"test".to_vec())));
+    let list = vec![
+        PlainValue::Integer(1),
+        PlainValue::Number(2.5),
+        PlainValue::Boolean(true),
+    ];
+    assert_eq!(get("list"), Some(PlainValue::Array(list)));
+    let empty = PlainValue::Map(vec![(
+        PlainValue::String(b"empty".to_vec()),
+        PlainValue::Array(vec![]),
+    )]);
+    assert_eq!(get("nested"), Some(empty));
+
+    // Plain values can be moved to another thread
+    let plain = std::thread::spawn(move || plain).join().unwrap();
+
+    // And back to Lua
+    lua.globals().set("t", plain)?;
+    lua.load(
+        r#"
+        assert(t.name == "test")
+        assert(#t.list == 3 and t.list[1] == 1 and t.list[2] == 2.5 and t.list[3] == true)
+        assert(next(t.nested.empty) == nil)
+    "#,
+    )
+    .exec()?;
+
+    // Shared (non-recursive) tables are allowed
+    let value = lua
+        .load("local s = {1}; return { a = s, b = s }")
+        .eval::<Value>()?;
+    assert!(value.to_plain().is_ok());
+
+    // Recursive tables are rejected
+    let value = lua.load("local t = {}; t.self = t; return t").eval::<Value>()?;
+    let err = value.to_plain().unwrap_err();
+    assert!(err.to_string().contains("recursive table detected"), "{err}");
+
+    // Non-plain values are rejected
+    let value = lua.load("{ f = print }").eval::<Value>()?;
+    assert!(value.to_plain().is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_plain_value_transfer() -> Result<()> {
+    let lua1 = Lua::new();
+    let lua2 = Lua::new();
+
+    let data = lua1
+        .load(r#"{ user = { name = "alice", tags = { "a", "b" } }, count = 2, ratio = 0.5 }"#)
+        .eval::<PlainValue>()?;
+    drop(lua1);
+
+    lua2.globals().set("data", data)?;
+    lua2.load(
+        r#"
+        assert(data.user.name == "alice")
+        assert(#data.user.tags == 2 and data.user.tags[2] == "b")
+        assert(data.count == 2 and data.ratio == 0.5)
+    "#,
+    )
+    .exec()?;
+
+    // Values that cannot be detached from the Lua state are rejected
+    let lua = Lua::new();
+    for code in ["{ f = print }", "{ co = coroutine.create(print) }"] {
+        let err = lua.load(code).eval::<PlainValue>().unwrap_err();
+        assert!(
+            err.to_string().contains("value has no plain representation"),
+            "{err}"
+        );
+    }
+    let ud = lua.create_any_userdata(0i32)?;
+    assert!(lua.unpack::<PlainValue>(Value::UserData(ud)).is_err());
+
+    Ok(())
+}